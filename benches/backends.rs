@@ -0,0 +1,85 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use linked_list_attempt::{ArenaLinkedList, LinkedList, UnrolledLinkedList};
+
+const ROUNDS: u32 = 5;
+const ITEMS: u64 = 1_000_000;
+
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+
+    start.elapsed() / ROUNDS
+}
+
+fn main() {
+    let boxed: LinkedList<u64> = (0..ITEMS).collect();
+    let arena: ArenaLinkedList<u64> = (0..ITEMS).collect();
+    let unrolled: UnrolledLinkedList<u64> = (0..ITEMS).collect();
+
+    let rows = [
+        (
+            "push",
+            time(|| {
+                let mut list = LinkedList::new();
+                (0..ITEMS).for_each(|v| list.push(v));
+                black_box(list);
+            }),
+            time(|| {
+                let mut list = ArenaLinkedList::new();
+                (0..ITEMS).for_each(|v| list.push(v));
+                black_box(list);
+            }),
+            time(|| {
+                let mut list = UnrolledLinkedList::new();
+                (0..ITEMS).for_each(|v| list.push(v));
+                black_box(list);
+            }),
+        ),
+        (
+            "iterate",
+            time(|| {
+                black_box(boxed.iter().sum::<u64>());
+            }),
+            time(|| {
+                black_box(arena.iter().sum::<u64>());
+            }),
+            time(|| {
+                black_box(unrolled.iter().sum::<u64>());
+            }),
+        ),
+        (
+            "pop_front",
+            time(|| {
+                let mut list = boxed.clone();
+                while let Some(v) = list.pop_front() {
+                    black_box(v);
+                }
+            }),
+            time(|| {
+                let mut list = arena.clone();
+                while let Some(v) = list.pop_front() {
+                    black_box(v);
+                }
+            }),
+            time(|| {
+                let mut list = unrolled.clone();
+                while let Some(v) = list.pop_front() {
+                    black_box(v);
+                }
+            }),
+        ),
+    ];
+
+    println!("{ITEMS} items");
+    println!(
+        "{:>10} {:>14} {:>14} {:>14}",
+        "", "boxed", "arena", "unrolled"
+    );
+    for (name, boxed, arena, unrolled) in rows {
+        println!("{name:>10} {boxed:>14.2?} {arena:>14.2?} {unrolled:>14.2?}");
+    }
+}
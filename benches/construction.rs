@@ -0,0 +1,36 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use linked_list_attempt::LinkedList;
+
+const ROUNDS: u32 = 5;
+
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+
+    start.elapsed() / ROUNDS
+}
+
+fn main() {
+    println!(
+        "{:>10} {:>14} {:>14} {:>14}",
+        "items", "collect", "from", "std collect"
+    );
+
+    for items in [10_000, 100_000, 1_000_000] {
+        let collect = time(|| {
+            black_box((0..items).collect::<LinkedList<u64>>());
+        });
+        let from = time(|| {
+            black_box(LinkedList::from(0..items));
+        });
+        let std_collect = time(|| {
+            black_box((0..items).collect::<std::collections::LinkedList<u64>>());
+        });
+
+        println!("{items:>10} {collect:>14.2?} {from:>14.2?} {std_collect:>14.2?}");
+    }
+}
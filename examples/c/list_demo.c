@@ -0,0 +1,37 @@
+#include <assert.h>
+#include <stdio.h>
+
+#include "linked_list_attempt.h"
+
+int main(void) {
+    LlList *list = ll_new();
+    int64_t out = 0;
+
+    for (int64_t v = 1; v <= 3; v++) {
+        ll_push(list, v);
+    }
+    ll_push_front(list, 0);
+    assert(ll_len(list) == 4);
+    assert(ll_get(list, 2, &out) && out == 2);
+
+    ll_reverse(list);
+    assert(ll_pop_front(list, &out) && out == 3);
+    assert(ll_pop(list, &out) && out == 0);
+    assert(!ll_get(list, 5, &out));
+
+    ll_clear(list);
+    assert(ll_len(list) == 0);
+    assert(!ll_pop_front(list, &out));
+    ll_free(list);
+
+    int payload = 41;
+    LlPtrList *pointers = ll_ptr_new();
+    ll_ptr_push(pointers, &payload);
+    *(int *)ll_ptr_pop_front(pointers) += 1;
+    assert(ll_ptr_pop_front(pointers) == NULL);
+    ll_ptr_free(pointers);
+    assert(payload == 42);
+
+    puts("ok");
+    return 0;
+}
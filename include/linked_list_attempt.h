@@ -0,0 +1,44 @@
+#ifndef LINKED_LIST_ATTEMPT_H
+#define LINKED_LIST_ATTEMPT_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct LlList LlList;
+
+typedef struct LlPtrList LlPtrList;
+
+LlList *ll_new(void);
+
+void ll_free(LlList *list);
+
+size_t ll_len(const LlList *list);
+
+void ll_push(LlList *list, int64_t value);
+
+void ll_push_front(LlList *list, int64_t value);
+
+bool ll_pop(LlList *list, int64_t *out);
+
+bool ll_pop_front(LlList *list, int64_t *out);
+
+bool ll_get(const LlList *list, size_t index, int64_t *out);
+
+void ll_clear(LlList *list);
+
+void ll_reverse(LlList *list);
+
+LlPtrList *ll_ptr_new(void);
+
+void ll_ptr_free(LlPtrList *list);
+
+size_t ll_ptr_len(const LlPtrList *list);
+
+void ll_ptr_push(LlPtrList *list, void *value);
+
+void *ll_ptr_pop_front(LlPtrList *list);
+
+#endif /* LINKED_LIST_ATTEMPT_H */
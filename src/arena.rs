@@ -0,0 +1,507 @@
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+use crate::macros::impl_list_traits;
+use crate::LinkedList;
+
+// Nodes live side by side in `slots` and link to each other by index. Slots
+// freed by pops are chained through `free` and reused by later pushes.
+#[derive(Clone)]
+pub struct ArenaLinkedList<T> {
+    slots: Vec<Slot<T>>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    free: Option<usize>,
+    len: usize,
+}
+
+#[derive(Clone)]
+enum Slot<T> {
+    Occupied { value: T, next: Option<usize> },
+    Vacant { next_free: Option<usize> },
+}
+
+impl<T> ArenaLinkedList<T> {
+    pub fn new() -> Self {
+        ArenaLinkedList::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        ArenaLinkedList {
+            slots: Vec::with_capacity(capacity),
+            head: None,
+            tail: None,
+            free: None,
+            len: 0,
+        }
+    }
+
+    fn alloc(&mut self, value: T, next: Option<usize>) -> usize {
+        let occupied = Slot::Occupied { value, next };
+
+        match self.free {
+            Some(index) => {
+                let Slot::Vacant { next_free } =
+                    std::mem::replace(&mut self.slots[index], occupied)
+                else {
+                    unreachable!("free list points at an occupied slot");
+                };
+                self.free = next_free;
+
+                index
+            }
+            None => {
+                self.slots.push(occupied);
+
+                self.slots.len() - 1
+            }
+        }
+    }
+
+    fn release(&mut self, index: usize) -> (T, Option<usize>) {
+        let vacant = Slot::Vacant {
+            next_free: self.free,
+        };
+        let Slot::Occupied { value, next } = std::mem::replace(&mut self.slots[index], vacant)
+        else {
+            unreachable!("released a vacant slot");
+        };
+        self.free = Some(index);
+
+        (value, next)
+    }
+
+    fn value(&self, index: usize) -> &T {
+        match &self.slots[index] {
+            Slot::Occupied { value, .. } => value,
+            Slot::Vacant { .. } => unreachable!("linked to a vacant slot"),
+        }
+    }
+
+    fn value_mut(&mut self, index: usize) -> &mut T {
+        match &mut self.slots[index] {
+            Slot::Occupied { value, .. } => value,
+            Slot::Vacant { .. } => unreachable!("linked to a vacant slot"),
+        }
+    }
+
+    fn next_mut(&mut self, index: usize) -> &mut Option<usize> {
+        match &mut self.slots[index] {
+            Slot::Occupied { next, .. } => next,
+            Slot::Vacant { .. } => unreachable!("linked to a vacant slot"),
+        }
+    }
+
+    pub fn push(&mut self, val: T) {
+        let index = self.alloc(val, None);
+
+        match self.tail {
+            Some(tail) => *self.next_mut(tail) = Some(index),
+            None => self.head = Some(index),
+        }
+        self.tail = Some(index);
+        self.len += 1;
+    }
+
+    pub fn push_front(&mut self, val: T) {
+        let index = self.alloc(val, self.head);
+
+        self.head = Some(index);
+        self.tail.get_or_insert(index);
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let tail = self.tail?;
+
+        // links only run forwards, so the new tail has to be found from the head
+        let mut before = None;
+        let mut cursor = self.head;
+        while let Some(index) = cursor.filter(|&index| index != tail) {
+            before = Some(index);
+            cursor = *self.next_mut(index);
+        }
+
+        match before {
+            Some(index) => *self.next_mut(index) = None,
+            None => self.head = None,
+        }
+        self.tail = before;
+
+        Some(self.unlinked(tail))
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head?;
+        let next = *self.next_mut(head);
+
+        self.head = next;
+        if next.is_none() {
+            self.tail = None;
+        }
+
+        Some(self.unlinked(head))
+    }
+
+    fn unlinked(&mut self, index: usize) -> T {
+        let (value, _) = self.release(index);
+        self.len -= 1;
+
+        // an empty list has nothing worth keeping in the arena
+        if self.len == 0 {
+            self.slots.clear();
+            self.free = None;
+        }
+
+        value
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|index| self.value(index))
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.map(|index| self.value_mut(index))
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|index| self.value(index))
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|index| self.value_mut(index))
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|v| v == value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.head = None;
+        self.tail = None;
+        self.free = None;
+        self.len = 0;
+    }
+
+    pub fn iter(&self) -> ArenaIter<'_, T> {
+        ArenaIter {
+            slots: &self.slots,
+            next: self.head,
+            len: self.len,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> ArenaIterMut<'_, T> {
+        ArenaIterMut {
+            slots: self.slots.as_mut_ptr(),
+            next: self.head,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for ArenaLinkedList<T> {
+    fn default() -> Self {
+        ArenaLinkedList::new()
+    }
+}
+
+impl_list_traits!(ArenaLinkedList);
+
+impl<T> FromIterator<T> for ArenaLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut list = ArenaLinkedList::with_capacity(iter.size_hint().0);
+        list.extend(iter);
+
+        list
+    }
+}
+
+impl<T> Extend<T> for ArenaLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|v| self.push(v));
+    }
+}
+
+impl<T> From<LinkedList<T>> for ArenaLinkedList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<ArenaLinkedList<T>> for LinkedList<T> {
+    fn from(list: ArenaLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> IntoIterator for ArenaLinkedList<T> {
+    type Item = T;
+    type IntoIter = ArenaIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ArenaIntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ArenaLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = ArenaIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut ArenaLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = ArenaIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+pub struct ArenaIter<'a, T> {
+    slots: &'a [Slot<T>],
+    next: Option<usize>,
+    len: usize,
+}
+
+impl<T> Clone for ArenaIter<'_, T> {
+    fn clone(&self) -> Self {
+        ArenaIter {
+            slots: self.slots,
+            next: self.next,
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T> Iterator for ArenaIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Slot::Occupied { value, next } = &self.slots[self.next?] else {
+            unreachable!("linked to a vacant slot");
+        };
+        self.next = *next;
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for ArenaIter<'_, T> {}
+
+impl<T> FusedIterator for ArenaIter<'_, T> {}
+
+pub struct ArenaIterMut<'a, T> {
+    slots: *mut Slot<T>,
+    next: Option<usize>,
+    len: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for ArenaIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next?;
+
+        // SAFETY: `index` is a live slot of the list mutably borrowed for 'a,
+        // and links never form a cycle, so each slot is handed out only once
+        let Slot::Occupied { value, next } = (unsafe { &mut *self.slots.add(index) }) else {
+            unreachable!("linked to a vacant slot");
+        };
+        self.next = *next;
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for ArenaIterMut<'_, T> {}
+
+impl<T> FusedIterator for ArenaIterMut<'_, T> {}
+
+pub struct ArenaIntoIter<T> {
+    list: ArenaLinkedList<T>,
+}
+
+impl<T> Iterator for ArenaIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T> ExactSizeIterator for ArenaIntoIter<T> {}
+
+impl<T> FusedIterator for ArenaIntoIter<T> {}
+
+#[cfg(test)]
+mod tests {
+    use speculoos::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn pushes_and_pops_at_both_ends() {
+        let mut under_test = ArenaLinkedList::new();
+
+        under_test.push(2);
+        under_test.push(3);
+        under_test.push_front(1);
+
+        assert_that(&under_test.len()).is_equal_to(3);
+        assert_that(&under_test.front()).contains_value(&1);
+        assert_that(&under_test.back()).contains_value(&3);
+        assert_that(&under_test.pop()).contains_value(3);
+        assert_that(&under_test.pop_front()).contains_value(1);
+        assert_that(&under_test.pop()).contains_value(2);
+        assert_that(&under_test.pop()).is_none();
+        assert_that(&under_test.pop_front()).is_none();
+        assert_that(&under_test.is_empty()).is_true();
+    }
+
+    #[test]
+    fn reuses_freed_slots() {
+        let mut under_test: ArenaLinkedList<i32> = (0..4).collect();
+
+        under_test.pop_front();
+        under_test.pop();
+        under_test.push(10);
+        under_test.push_front(-1);
+
+        assert_that(&under_test.slots.len()).is_equal_to(4);
+        assert_that(&under_test.iter().copied().collect::<Vec<_>>())
+            .is_equal_to(vec![-1, 1, 2, 10]);
+    }
+
+    #[test]
+    fn releases_arena_once_emptied() {
+        let mut under_test: ArenaLinkedList<i32> = (0..3).collect();
+
+        while under_test.pop_front().is_some() {}
+        under_test.push(7);
+
+        assert_that(&under_test.slots.len()).is_equal_to(1);
+        assert_that(&under_test.front()).contains_value(&7);
+    }
+
+    #[test]
+    fn clears_list() {
+        let mut under_test: ArenaLinkedList<String> =
+            ["a", "b"].map(String::from).into_iter().collect();
+
+        under_test.clear();
+
+        assert_that(&under_test.is_empty()).is_true();
+        assert_that(&under_test.front()).is_none();
+        assert_that(&under_test.slots.len()).is_equal_to(0);
+    }
+
+    #[test]
+    fn iterates_in_link_order() {
+        let mut under_test = ArenaLinkedList::new();
+        under_test.push(2);
+        under_test.push_front(1);
+        under_test.push(3);
+
+        assert_that(&under_test.iter().collect::<Vec<_>>()).is_equal_to(vec![&1, &2, &3]);
+        assert_that(&under_test.get(1)).contains_value(&2);
+        assert_that(&under_test.contains(&4)).is_false();
+        assert_that(&under_test.iter().len()).is_equal_to(3);
+    }
+
+    #[test]
+    fn mutates_elements_through_iter_mut() {
+        let mut under_test: ArenaLinkedList<i32> = (1..=3).collect();
+
+        under_test.iter_mut().for_each(|v| *v *= 10);
+        for v in &mut under_test {
+            *v += 1;
+        }
+        *under_test.back_mut().unwrap() = 0;
+
+        assert_that(&under_test.into_iter().collect::<Vec<_>>()).is_equal_to(vec![11, 21, 0]);
+    }
+
+    #[test]
+    fn compares_and_formats_like_linked_list() {
+        let under_test: ArenaLinkedList<i32> = (1..=3).collect();
+
+        assert_that(&under_test).is_equal_to(under_test.clone());
+        assert_that(&(under_test == (1..=2).collect())).is_false();
+        assert_that(&format!("{under_test:?}"))
+            .is_equal_to("ArenaLinkedList [1, 2, 3]".to_string());
+    }
+
+    #[test]
+    fn displays_orders_and_hashes_like_linked_list() {
+        use std::collections::HashSet;
+
+        let under_test: ArenaLinkedList<i32> = (1..=3).collect();
+
+        assert_that(&under_test.to_string())
+            .is_equal_to(LinkedList::from(vec![1, 2, 3]).to_string());
+        assert_that(&(under_test < (1..=4).collect())).is_true();
+        assert_that(&under_test.cmp(&under_test.clone())).is_equal_to(std::cmp::Ordering::Equal);
+        let set: HashSet<_> = [under_test.clone(), under_test].into_iter().collect();
+        assert_that(&set.len()).is_equal_to(1);
+    }
+
+    #[test]
+    fn converts_to_and_from_linked_list() {
+        let under_test = ArenaLinkedList::from(LinkedList::from(vec![1, 2, 3]));
+
+        assert_that(&under_test.len()).is_equal_to(3);
+        let converted: LinkedList<_> = under_test.into();
+
+        assert_that(&converted).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn handles_millions_of_elements() {
+        let mut under_test: ArenaLinkedList<u32> = (0..5_000_000).collect();
+
+        assert_that(&under_test.back()).contains_value(&4_999_999);
+        assert_that(&under_test.iter().map(|&v| u64::from(v)).sum::<u64>())
+            .is_equal_to(4_999_999 * 5_000_000 / 2);
+        under_test.clear();
+        assert_that(&under_test.is_empty()).is_true();
+    }
+}
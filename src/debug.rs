@@ -0,0 +1,87 @@
+use std::iter::FusedIterator;
+
+use crate::{LinkedList, Node};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum NodeKind {
+    Tail,
+    Parent,
+}
+
+pub struct Nodes<'a, T> {
+    node: Option<&'a Node<T>>,
+    depth: usize,
+}
+
+impl<T> LinkedList<T> {
+    pub fn nodes(&self) -> Nodes<'_, T> {
+        Nodes {
+            node: self.head_node(),
+            depth: 0,
+        }
+    }
+}
+
+impl<T> Clone for Nodes<'_, T> {
+    fn clone(&self) -> Self {
+        Nodes {
+            node: self.node,
+            depth: self.depth,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Nodes<'a, T> {
+    type Item = (usize, NodeKind, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node.take()?;
+        self.node = node.next();
+        let kind = match self.node {
+            Some(_) => NodeKind::Parent,
+            None => NodeKind::Tail,
+        };
+        let depth = self.depth;
+        self.depth += 1;
+
+        Some((depth, kind, &node.value))
+    }
+}
+
+impl<T> FusedIterator for Nodes<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use speculoos::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn yields_no_nodes_for_empty_list() {
+        let under_test: LinkedList<i32> = LinkedList::new();
+
+        assert_that(&under_test.nodes().next()).is_none();
+    }
+
+    #[test]
+    fn yields_single_tail_node_for_singleton_list() {
+        let under_test = LinkedList::from(vec![1]);
+
+        assert_that(&under_test.nodes().collect::<Vec<_>>()).is_equal_to(vec![(
+            0,
+            NodeKind::Tail,
+            &1,
+        )]);
+    }
+
+    #[test]
+    fn yields_parent_chain_ending_in_tail() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.nodes().collect::<Vec<_>>()).is_equal_to(vec![
+            (0, NodeKind::Parent, &1),
+            (1, NodeKind::Parent, &2),
+            (2, NodeKind::Tail, &3),
+        ]);
+    }
+}
@@ -0,0 +1,207 @@
+use std::io::{self, Read, Write};
+
+use crate::LinkedList;
+
+pub trait Encode {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()>;
+}
+
+pub trait Decode: Sized {
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self>;
+}
+
+macro_rules! impl_encode_decode_for_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl Encode for $t {
+                fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+                    w.write_all(&self.to_le_bytes())
+                }
+            }
+
+            impl Decode for $t {
+                fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+                    let mut bytes = [0; std::mem::size_of::<$t>()];
+                    r.read_exact(&mut bytes)?;
+                    Ok(<$t>::from_le_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_encode_decode_for_primitive!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+// lengths go over the wire as u64 so encodings don't depend on the platform
+fn encode_len<W: Write>(len: usize, w: &mut W) -> io::Result<()> {
+    (len as u64).encode(w)
+}
+
+fn decode_len<R: Read>(r: &mut R) -> io::Result<usize> {
+    usize::try_from(u64::decode(r)?).map_err(|_| invalid_data("length does not fit in usize"))
+}
+
+impl Encode for bool {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        u8::from(*self).encode(w)
+    }
+}
+
+impl Decode for bool {
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        match u8::decode(r)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid_data("invalid bool")),
+        }
+    }
+}
+
+impl Encode for char {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        u32::from(*self).encode(w)
+    }
+}
+
+impl Decode for char {
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        char::from_u32(u32::decode(r)?).ok_or_else(|| invalid_data("invalid char"))
+    }
+}
+
+impl Encode for String {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        encode_len(self.len(), w)?;
+        w.write_all(self.as_bytes())
+    }
+}
+
+impl Decode for String {
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        let len = decode_len(r)?;
+        let mut bytes = Vec::new();
+        r.take(len as u64).read_to_end(&mut bytes)?;
+
+        if bytes.len() != len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        String::from_utf8(bytes).map_err(|_| invalid_data("invalid utf-8 string"))
+    }
+}
+
+impl<T: Encode> Encode for LinkedList<T> {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        encode_len(self.len, w)?;
+        self.iter().try_for_each(|value| value.encode(w))
+    }
+}
+
+impl<T: Decode> Decode for LinkedList<T> {
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        let len = decode_len(r)?;
+        let mut list = LinkedList::new();
+
+        for _ in 0..len {
+            list.push(T::decode(r)?);
+        }
+
+        Ok(list)
+    }
+}
+
+impl<T: Encode> LinkedList<T> {
+    pub fn write_to<W: Write>(&self, mut w: W) -> io::Result<()> {
+        self.encode(&mut w)
+    }
+}
+
+impl<T: Decode> LinkedList<T> {
+    pub fn read_from<R: Read>(mut r: R) -> io::Result<Self> {
+        LinkedList::decode(&mut r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use speculoos::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn writes_length_prefix_then_elements() {
+        let mut bytes = Vec::new();
+
+        LinkedList::from(vec![1u16, 258])
+            .write_to(&mut bytes)
+            .unwrap();
+
+        assert_that(&bytes).is_equal_to(vec![2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 1]);
+    }
+
+    #[test]
+    fn round_trips_primitive_lists() {
+        let under_test = LinkedList::from(vec![-1.5f64, 0.0, f64::MAX]);
+        let mut bytes = Vec::new();
+
+        under_test.write_to(&mut bytes).unwrap();
+
+        assert_that(&LinkedList::<f64>::read_from(bytes.as_slice()).unwrap())
+            .is_equal_to(under_test);
+    }
+
+    #[test]
+    fn round_trips_strings_chars_and_bools() {
+        let strings = LinkedList::from(vec!["héllo".to_string(), String::new()]);
+        let chars = LinkedList::from(vec!['a', '€']);
+        let bools = LinkedList::from(vec![true, false]);
+        let mut bytes = Vec::new();
+
+        strings.write_to(&mut bytes).unwrap();
+        chars.write_to(&mut bytes).unwrap();
+        bools.write_to(&mut bytes).unwrap();
+        let mut reader = bytes.as_slice();
+
+        assert_that(&LinkedList::<String>::read_from(&mut reader).unwrap()).is_equal_to(strings);
+        assert_that(&LinkedList::<char>::read_from(&mut reader).unwrap()).is_equal_to(chars);
+        assert_that(&LinkedList::<bool>::read_from(&mut reader).unwrap()).is_equal_to(bools);
+        assert_that(&reader.is_empty()).is_true();
+    }
+
+    #[test]
+    fn round_trips_nested_lists() {
+        let under_test = LinkedList::from(vec![LinkedList::from(vec![1i32, 2]), LinkedList::new()]);
+        let mut bytes = Vec::new();
+
+        under_test.write_to(&mut bytes).unwrap();
+
+        assert_that(&LinkedList::<LinkedList<i32>>::read_from(bytes.as_slice()).unwrap())
+            .is_equal_to(under_test);
+    }
+
+    #[test]
+    fn fails_to_read_truncated_input() {
+        let mut bytes = Vec::new();
+        LinkedList::from(vec![1u32, 2, 3])
+            .write_to(&mut bytes)
+            .unwrap();
+        bytes.truncate(bytes.len() - 1);
+
+        let error = LinkedList::<u32>::read_from(bytes.as_slice()).unwrap_err();
+
+        assert_that(&error.kind()).is_equal_to(io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn fails_to_read_invalid_elements() {
+        let bytes = [1, 0, 0, 0, 0, 0, 0, 0, 2];
+
+        let error = LinkedList::<bool>::read_from(&bytes[..]).unwrap_err();
+
+        assert_that(&error.kind()).is_equal_to(io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,249 @@
+use std::ffi::c_void;
+use std::ptr;
+
+use crate::LinkedList;
+
+// Opaque handles: C only ever sees pointers to these, so cbindgen emits
+// forward declarations and the layout stays private to Rust.
+pub struct LlList(LinkedList<i64>);
+
+pub struct LlPtrList(LinkedList<*mut c_void>);
+
+#[no_mangle]
+pub extern "C" fn ll_new() -> *mut LlList {
+    Box::into_raw(Box::new(LlList(LinkedList::new())))
+}
+
+/// # Safety
+///
+/// `list` must be null or a handle returned by `ll_new` that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn ll_free(list: *mut LlList) {
+    if !list.is_null() {
+        drop(Box::from_raw(list));
+    }
+}
+
+/// # Safety
+///
+/// `list` must be null or a live handle returned by `ll_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_len(list: *const LlList) -> usize {
+    list.as_ref().map_or(0, |list| list.0.len())
+}
+
+/// # Safety
+///
+/// `list` must be null or a live handle returned by `ll_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_push(list: *mut LlList, value: i64) {
+    if let Some(list) = list.as_mut() {
+        list.0.push(value);
+    }
+}
+
+/// # Safety
+///
+/// `list` must be null or a live handle returned by `ll_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_push_front(list: *mut LlList, value: i64) {
+    if let Some(list) = list.as_mut() {
+        list.0.push_front(value);
+    }
+}
+
+/// # Safety
+///
+/// `list` must be null or a live handle returned by `ll_new`, and `out` must
+/// be null or valid for a write of one `i64`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_pop(list: *mut LlList, out: *mut i64) -> bool {
+    write_out(list.as_mut().and_then(|list| list.0.pop()), out)
+}
+
+/// # Safety
+///
+/// `list` must be null or a live handle returned by `ll_new`, and `out` must
+/// be null or valid for a write of one `i64`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_pop_front(list: *mut LlList, out: *mut i64) -> bool {
+    write_out(list.as_mut().and_then(|list| list.0.pop_front()), out)
+}
+
+/// # Safety
+///
+/// `list` must be null or a live handle returned by `ll_new`, and `out` must
+/// be null or valid for a write of one `i64`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_get(list: *const LlList, index: usize, out: *mut i64) -> bool {
+    write_out(
+        list.as_ref().and_then(|list| list.0.get(index).copied()),
+        out,
+    )
+}
+
+/// # Safety
+///
+/// `list` must be null or a live handle returned by `ll_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_clear(list: *mut LlList) {
+    if let Some(list) = list.as_mut() {
+        list.0.clear();
+    }
+}
+
+/// # Safety
+///
+/// `list` must be null or a live handle returned by `ll_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_reverse(list: *mut LlList) {
+    if let Some(list) = list.as_mut() {
+        list.0.reverse();
+    }
+}
+
+unsafe fn write_out(value: Option<i64>, out: *mut i64) -> bool {
+    match value {
+        Some(value) => {
+            if !out.is_null() {
+                out.write(value);
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+// The pointer list never dereferences or frees its payloads; ownership of
+// whatever they point at stays with the caller.
+#[no_mangle]
+pub extern "C" fn ll_ptr_new() -> *mut LlPtrList {
+    Box::into_raw(Box::new(LlPtrList(LinkedList::new())))
+}
+
+/// # Safety
+///
+/// `list` must be null or a handle returned by `ll_ptr_new` that has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn ll_ptr_free(list: *mut LlPtrList) {
+    if !list.is_null() {
+        drop(Box::from_raw(list));
+    }
+}
+
+/// # Safety
+///
+/// `list` must be null or a live handle returned by `ll_ptr_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_ptr_len(list: *const LlPtrList) -> usize {
+    list.as_ref().map_or(0, |list| list.0.len())
+}
+
+/// # Safety
+///
+/// `list` must be null or a live handle returned by `ll_ptr_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_ptr_push(list: *mut LlPtrList, value: *mut c_void) {
+    if let Some(list) = list.as_mut() {
+        list.0.push(value);
+    }
+}
+
+/// Returns null when the list is empty.
+///
+/// # Safety
+///
+/// `list` must be null or a live handle returned by `ll_ptr_new`.
+#[no_mangle]
+pub unsafe extern "C" fn ll_ptr_pop_front(list: *mut LlPtrList) -> *mut c_void {
+    list.as_mut()
+        .and_then(|list| list.0.pop_front())
+        .unwrap_or(ptr::null_mut())
+}
+
+#[cfg(test)]
+mod tests {
+    use speculoos::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn drives_integer_list_through_handle() {
+        unsafe {
+            let under_test = ll_new();
+            ll_push(under_test, 2);
+            ll_push(under_test, 3);
+            ll_push_front(under_test, 1);
+            let mut out = 0;
+
+            assert_that(&ll_len(under_test)).is_equal_to(3);
+            assert_that(&ll_get(under_test, 1, &mut out)).is_true();
+            assert_that(&out).is_equal_to(2);
+            assert_that(&ll_pop_front(under_test, &mut out)).is_true();
+            assert_that(&out).is_equal_to(1);
+            assert_that(&ll_pop(under_test, &mut out)).is_true();
+            assert_that(&out).is_equal_to(3);
+            ll_free(under_test);
+        }
+    }
+
+    #[test]
+    fn reports_empty_list_without_writing_output() {
+        unsafe {
+            let under_test = ll_new();
+            let mut out = 7;
+
+            assert_that(&ll_pop_front(under_test, &mut out)).is_false();
+            assert_that(&ll_get(under_test, 0, &mut out)).is_false();
+            assert_that(&out).is_equal_to(7);
+            ll_free(under_test);
+        }
+    }
+
+    #[test]
+    fn tolerates_null_handles() {
+        unsafe {
+            ll_push(ptr::null_mut(), 1);
+            ll_free(ptr::null_mut());
+
+            assert_that(&ll_len(ptr::null())).is_equal_to(0);
+            assert_that(&ll_pop(ptr::null_mut(), ptr::null_mut())).is_false();
+            assert_that(&ll_ptr_pop_front(ptr::null_mut()).is_null()).is_true();
+        }
+    }
+
+    #[test]
+    fn clears_and_reverses_through_handle() {
+        unsafe {
+            let under_test = ll_new();
+            (1..=3).for_each(|v| ll_push(under_test, v));
+            let mut out = 0;
+
+            ll_reverse(under_test);
+            ll_pop_front(under_test, &mut out);
+            assert_that(&out).is_equal_to(3);
+            ll_clear(under_test);
+            assert_that(&ll_len(under_test)).is_equal_to(0);
+            ll_free(under_test);
+        }
+    }
+
+    #[test]
+    fn stores_pointer_payloads_without_owning_them() {
+        let mut values = [10, 20];
+
+        unsafe {
+            let under_test = ll_ptr_new();
+            values
+                .iter_mut()
+                .for_each(|v| ll_ptr_push(under_test, v as *mut i32 as *mut c_void));
+
+            assert_that(&ll_ptr_len(under_test)).is_equal_to(2);
+            let first = ll_ptr_pop_front(under_test) as *mut i32;
+            *first += 1;
+            ll_ptr_free(under_test);
+        }
+
+        assert_that(&values).is_equal_to([11, 20]);
+    }
+}
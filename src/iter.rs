@@ -0,0 +1,788 @@
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+
+use crate::{Link, LinkedList, Node};
+
+pub struct Iter<'a, T> {
+    node: Option<&'a Node<T>>,
+    len: usize,
+    back: Vec<&'a T>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    pub(crate) fn new(node: Option<&'a Node<T>>, len: usize) -> Self {
+        Iter {
+            node,
+            len,
+            back: Vec::new(),
+        }
+    }
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            node: self.node,
+            len: self.len,
+            back: self.back.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let node = self.node.take()?;
+        self.node = node.next();
+        self.len -= 1;
+
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // singly linked nodes cannot be walked backwards, so the first call
+        // buffers every remaining reference and later calls pop from that
+        // buffer: O(n) time and O(n) extra memory up front, O(1) afterwards.
+        // Cloning a partly reversed iterator copies the buffer as well.
+        if self.back.is_empty() {
+            let front = Iter {
+                node: self.node,
+                len: self.len,
+                back: Vec::new(),
+            };
+            self.back = front.collect();
+        }
+        self.len -= 1;
+
+        self.back.pop()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+pub struct IterMut<'a, T> {
+    node: Option<&'a mut Node<T>>,
+    len: usize,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    pub(crate) fn new(node: Option<&'a mut Node<T>>, len: usize) -> Self {
+        IterMut { node, len }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node.take()?;
+        // SAFETY: a node owns its successor, and the iterator's unique borrow
+        // of the list extends to it; only `next` is read, so the value handed
+        // out below stays unaliased
+        self.node = node.next.map(|next| unsafe { &mut *next.as_ptr() });
+        self.len -= 1;
+
+        Some(&mut node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+pub struct IntoIter<T> {
+    list: LinkedList<T>,
+    back: VecDeque<T>,
+}
+
+impl<T> IntoIter<T> {
+    pub(crate) fn new(list: LinkedList<T>) -> Self {
+        IntoIter {
+            list,
+            back: VecDeque::new(),
+        }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front().or_else(|| self.back.pop_front())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len() + self.back.len();
+
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // as with `Iter`, the first call moves every remaining value into a
+        // buffer in O(n) time and memory; later calls are O(1)
+        if self.back.is_empty() {
+            while let Some(value) = self.list.pop_front() {
+                self.back.push_back(value);
+            }
+        }
+        self.back.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+pub struct ChunkBy<T, F> {
+    list: LinkedList<T>,
+    pred: F,
+}
+
+impl<T, F> ChunkBy<T, F> {
+    pub(crate) fn new(list: LinkedList<T>, pred: F) -> Self {
+        ChunkBy { list, pred }
+    }
+}
+
+impl<T, F: FnMut(&T, &T) -> bool> Iterator for ChunkBy<T, F> {
+    type Item = LinkedList<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.list.is_empty() {
+            return None;
+        }
+
+        let run_len = 1 + self
+            .list
+            .iter()
+            .zip(self.list.iter().skip(1))
+            .take_while(|(a, b)| (self.pred)(a, b))
+            .count();
+        let rest = self.list.detach_from(run_len);
+
+        Some(std::mem::replace(&mut self.list, rest))
+    }
+}
+
+impl<T, F: FnMut(&T, &T) -> bool> FusedIterator for ChunkBy<T, F> {}
+
+pub struct Split<T, F> {
+    list: LinkedList<T>,
+    pred: F,
+    finished: bool,
+}
+
+impl<T, F> Split<T, F> {
+    pub(crate) fn new(list: LinkedList<T>, pred: F) -> Self {
+        Split {
+            list,
+            pred,
+            finished: false,
+        }
+    }
+}
+
+impl<T, F: FnMut(&T) -> bool> Iterator for Split<T, F> {
+    type Item = LinkedList<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match self.list.iter().position(&mut self.pred) {
+            Some(index) => {
+                let mut rest = self.list.detach_from(index);
+                rest.pop_front();
+
+                Some(std::mem::replace(&mut self.list, rest))
+            }
+            None => {
+                self.finished = true;
+
+                Some(std::mem::take(&mut self.list))
+            }
+        }
+    }
+}
+
+impl<T, F: FnMut(&T) -> bool> FusedIterator for Split<T, F> {}
+
+pub struct Windows<'a, T> {
+    start: Iter<'a, T>,
+    size: usize,
+}
+
+impl<'a, T> Windows<'a, T> {
+    pub(crate) fn new(start: Iter<'a, T>, size: usize) -> Self {
+        Windows { start, size }
+    }
+}
+
+impl<T> Clone for Windows<'_, T> {
+    fn clone(&self) -> Self {
+        Windows {
+            start: self.start.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start.len() < self.size {
+            return None;
+        }
+
+        let window = self.start.clone().take(self.size).collect();
+        self.start.next();
+
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.start.len() + 1).saturating_sub(self.size);
+
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for Windows<'_, T> {}
+
+impl<T> FusedIterator for Windows<'_, T> {}
+
+pub struct Drain<'a, T> {
+    list: &'a mut LinkedList<T>,
+}
+
+impl<'a, T> Drain<'a, T> {
+    pub(crate) fn new(list: &'a mut LinkedList<T>) -> Self {
+        Drain { list }
+    }
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> FusedIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        self.list.clear();
+    }
+}
+
+pub struct ExtractIf<'a, T, F> {
+    list: &'a mut LinkedList<T>,
+    // the last kept node, which becomes the tail if everything after it goes
+    prev: Link<T>,
+    node: Link<T>,
+    pred: F,
+}
+
+impl<'a, T, F> ExtractIf<'a, T, F> {
+    pub(crate) fn new(list: &'a mut LinkedList<T>, pred: F) -> Self {
+        ExtractIf {
+            node: list.head,
+            list,
+            prev: None,
+            pred,
+        }
+    }
+}
+
+impl<T, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'_, T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.node {
+            // SAFETY: `node` and `prev` are live nodes of the mutably borrowed
+            // list, and `node` is freed only after it has been unlinked
+            let next = unsafe { (*node.as_ptr()).next };
+            self.node = next;
+
+            if !(self.pred)(unsafe { &mut (*node.as_ptr()).value }) {
+                self.prev = Some(node);
+                continue;
+            }
+
+            match self.prev {
+                Some(prev) => unsafe { (*prev.as_ptr()).next = next },
+                None => self.list.head = next,
+            }
+            if next.is_none() {
+                self.list.tail = self.prev;
+            }
+            self.list.len -= 1;
+
+            return Some(unsafe { Node::free(node) }.value);
+        }
+
+        None
+    }
+}
+
+pub struct IntoChunks<T> {
+    list: LinkedList<T>,
+    chunk_size: usize,
+}
+
+impl<T> IntoChunks<T> {
+    pub(crate) fn new(list: LinkedList<T>, chunk_size: usize) -> Self {
+        IntoChunks { list, chunk_size }
+    }
+}
+
+impl<T> Iterator for IntoChunks<T> {
+    type Item = LinkedList<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.list.is_empty() {
+            return None;
+        }
+
+        let rest = self.list.detach_from(self.chunk_size);
+
+        Some(std::mem::replace(&mut self.list, rest))
+    }
+}
+
+impl<T> FusedIterator for IntoChunks<T> {}
+
+#[cfg(test)]
+mod tests {
+    use speculoos::prelude::*;
+
+    use crate::LinkedList;
+
+    #[test]
+    fn iterates_over_empty_list() {
+        let under_test: LinkedList<i32> = LinkedList::new();
+
+        assert_that(&under_test.iter().next()).is_none();
+    }
+
+    #[test]
+    fn iterates_over_multi_list_in_order() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.iter().collect::<Vec<_>>()).is_equal_to(vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn iterating_does_not_consume_list() {
+        let under_test = LinkedList::from(vec![1, 2]);
+
+        under_test.iter().for_each(drop);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2]));
+    }
+
+    #[test]
+    fn consumes_list_into_iterator_in_order() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.into_iter().collect::<Vec<_>>()).is_equal_to(vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn consumes_list_in_for_loop() {
+        let mut values = vec![];
+
+        for value in LinkedList::from(vec![1, 2]) {
+            values.push(value);
+        }
+
+        assert_that(&values).is_equal_to(vec![1, 2]);
+    }
+
+    #[test]
+    fn composes_owned_iterator_with_adapters() {
+        let under_test = LinkedList::from(vec![1, 2, 3, 4]);
+
+        assert_that(
+            &under_test
+                .into_iter()
+                .filter(|v| v % 2 == 0)
+                .map(|v| v * 10)
+                .collect::<Vec<_>>(),
+        )
+        .is_equal_to(vec![20, 40]);
+    }
+
+    #[test]
+    fn mutates_elements_through_mutable_iterator() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        under_test.iter_mut().for_each(|v| *v *= 2);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![2, 4, 6]));
+    }
+
+    #[test]
+    fn iterates_over_borrowed_list_in_for_loop() {
+        let under_test = LinkedList::from(vec![1, 2]);
+        let mut values = vec![];
+
+        for value in &under_test {
+            values.push(*value);
+        }
+
+        assert_that(&values).is_equal_to(vec![1, 2]);
+    }
+
+    #[test]
+    fn mutates_mutably_borrowed_list_in_for_loop() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        for value in &mut under_test {
+            *value += 1;
+        }
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![2, 3]));
+    }
+
+    #[test]
+    fn borrowed_list_is_accepted_as_generic_into_iterator() {
+        fn sum<'a, I: IntoIterator<Item = &'a i32>>(it: I) -> i32 {
+            it.into_iter().sum()
+        }
+
+        assert_that(&sum(&LinkedList::from(vec![1, 2, 3]))).is_equal_to(6);
+    }
+
+    #[test]
+    fn reports_exact_length_as_items_are_consumed() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+        let mut iter = under_test.iter();
+
+        assert_that(&iter.len()).is_equal_to(3);
+        iter.next();
+        assert_that(&iter.len()).is_equal_to(2);
+        assert_that(&iter.size_hint()).is_equal_to((2, Some(2)));
+    }
+
+    #[test]
+    fn reports_exact_length_of_mutable_iterator() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+        let mut iter = under_test.iter_mut();
+
+        iter.next();
+
+        assert_that(&iter.len()).is_equal_to(1);
+    }
+
+    #[test]
+    fn reports_exact_length_of_owned_iterator() {
+        let mut iter = LinkedList::from(vec![1, 2, 3]).into_iter();
+
+        iter.next();
+        iter.next();
+
+        assert_that(&iter.len()).is_equal_to(1);
+    }
+
+    #[test]
+    fn keeps_returning_none_once_exhausted() {
+        let under_test = LinkedList::from(vec![1]);
+        let mut iter = under_test.iter();
+
+        iter.next();
+
+        assert_that(&iter.next()).is_none();
+        assert_that(&iter.next()).is_none();
+        assert_that(&iter.len()).is_equal_to(0);
+    }
+
+    #[test]
+    fn iterates_over_list_in_reverse() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.iter().rev().collect::<Vec<_>>()).is_equal_to(vec![&3, &2, &1]);
+    }
+
+    #[test]
+    fn iterates_from_both_ends_without_overlap() {
+        let under_test = LinkedList::from(vec![1, 2, 3, 4]);
+        let mut iter = under_test.iter();
+
+        assert_that(&iter.next_back()).contains(&4);
+        assert_that(&iter.next()).contains(&1);
+        assert_that(&iter.next_back()).contains(&3);
+        assert_that(&iter.next()).contains(&2);
+        assert_that(&iter.next()).is_none();
+        assert_that(&iter.next_back()).is_none();
+    }
+
+    #[test]
+    fn consumes_list_in_reverse() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.into_iter().rev().collect::<Vec<_>>()).is_equal_to(vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn consumes_list_from_both_ends_without_overlap() {
+        let mut iter = LinkedList::from(vec![1, 2, 3]).into_iter();
+
+        assert_that(&iter.next()).contains(1);
+        assert_that(&iter.next_back()).contains(3);
+        assert_that(&iter.len()).is_equal_to(1);
+        assert_that(&iter.next()).contains(2);
+        assert_that(&iter.next_back()).is_none();
+    }
+
+    #[test]
+    fn drains_elements_in_order() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.drain().collect::<Vec<_>>()).is_equal_to(vec![1, 2, 3]);
+        assert_that(&under_test.size()).is_equal_to(0);
+    }
+
+    #[test]
+    fn empties_list_when_partially_consumed_drain_is_dropped() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        let mut drain = under_test.drain();
+        assert_that(&drain.next()).contains(1);
+        assert_that(&drain.len()).is_equal_to(2);
+        drop(drain);
+
+        assert_that(&under_test).is_equal_to(LinkedList::new());
+    }
+
+    #[test]
+    fn leaves_list_usable_after_drain() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        under_test.drain();
+        under_test.push(3);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![3]));
+    }
+
+    #[test]
+    fn extracts_matching_elements_in_order() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3, 4, 5, 6]);
+
+        let extracted: Vec<_> = under_test.extract_if(|v| *v % 2 == 0).collect();
+
+        assert_that(&extracted).is_equal_to(vec![2, 4, 6]);
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 3, 5]));
+    }
+
+    #[test]
+    fn extracts_consecutive_matches_at_head_and_tail() {
+        let mut under_test = LinkedList::from(vec![0, 0, 1, 0, 0]);
+
+        let extracted: Vec<_> = under_test.extract_if(|v| *v == 0).collect();
+
+        assert_that(&extracted).is_equal_to(vec![0, 0, 0, 0]);
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1]));
+        assert_that(&under_test.pop()).contains(1);
+    }
+
+    #[test]
+    fn extracts_every_element() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.extract_if(|_| true).count()).is_equal_to(3);
+        assert_that(&under_test).is_equal_to(LinkedList::new());
+    }
+
+    #[test]
+    fn keeps_unvisited_elements_when_extraction_stops_early() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3, 4]);
+
+        assert_that(&under_test.extract_if(|v| *v > 1).next()).contains(2);
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 3, 4]));
+    }
+
+    #[test]
+    fn splits_list_into_chunks() {
+        let under_test = LinkedList::from(vec![1, 2, 3, 4, 5]);
+
+        assert_that(&under_test.into_chunks(2).collect::<Vec<_>>()).is_equal_to(vec![
+            LinkedList::from(vec![1, 2]),
+            LinkedList::from(vec![3, 4]),
+            LinkedList::from(vec![5]),
+        ]);
+    }
+
+    #[test]
+    fn yields_whole_list_when_chunk_size_exceeds_length() {
+        let under_test = LinkedList::from(vec![1, 2]);
+
+        assert_that(&under_test.into_chunks(5).collect::<Vec<_>>())
+            .is_equal_to(vec![LinkedList::from(vec![1, 2])]);
+    }
+
+    #[test]
+    fn yields_no_chunks_for_empty_list() {
+        let under_test: LinkedList<i32> = LinkedList::new();
+
+        assert_that(&under_test.into_chunks(3).next()).is_none();
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn panics_when_chunk_size_is_zero() {
+        LinkedList::from(vec![1]).into_chunks(0);
+    }
+
+    #[test]
+    fn yields_overlapping_windows() {
+        let under_test = LinkedList::from(vec![1, 2, 3, 4]);
+
+        assert_that(&under_test.windows(2).collect::<Vec<_>>()).is_equal_to(vec![
+            vec![&1, &2],
+            vec![&2, &3],
+            vec![&3, &4],
+        ]);
+    }
+
+    #[test]
+    fn yields_no_windows_when_size_exceeds_length() {
+        let under_test = LinkedList::from(vec![1, 2]);
+
+        assert_that(&under_test.windows(3).next()).is_none();
+        assert_that(&under_test.windows(3).len()).is_equal_to(0);
+    }
+
+    #[test]
+    fn reports_exact_number_of_windows() {
+        let under_test = LinkedList::from(vec![1, 2, 3, 4, 5]);
+
+        assert_that(&under_test.windows(3).len()).is_equal_to(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn panics_when_window_size_is_zero() {
+        LinkedList::from(vec![1]).windows(0);
+    }
+
+    #[test]
+    fn splits_list_on_matching_elements() {
+        let under_test = LinkedList::from(vec!["a", "b", "", "c", "", "d"]);
+
+        assert_that(&under_test.split(|s| s.is_empty()).collect::<Vec<_>>()).is_equal_to(vec![
+            LinkedList::from(vec!["a", "b"]),
+            LinkedList::from(vec!["c"]),
+            LinkedList::from(vec!["d"]),
+        ]);
+    }
+
+    #[test]
+    fn yields_empty_segments_around_leading_trailing_and_adjacent_delimiters() {
+        let under_test = LinkedList::from(vec![0, 1, 0, 0]);
+
+        assert_that(&under_test.split(|v| *v == 0).collect::<Vec<_>>()).is_equal_to(vec![
+            LinkedList::new(),
+            LinkedList::from(vec![1]),
+            LinkedList::new(),
+            LinkedList::new(),
+        ]);
+    }
+
+    #[test]
+    fn yields_single_empty_segment_for_empty_list() {
+        let under_test: LinkedList<i32> = LinkedList::new();
+
+        assert_that(&under_test.split(|v| *v == 0).collect::<Vec<_>>())
+            .is_equal_to(vec![LinkedList::new()]);
+    }
+
+    #[test]
+    fn groups_runs_of_equal_elements() {
+        let under_test = LinkedList::from(vec![1, 1, 2, 3, 3, 3, 1]);
+
+        assert_that(&under_test.chunk_by(|a, b| a == b).collect::<Vec<_>>()).is_equal_to(vec![
+            LinkedList::from(vec![1, 1]),
+            LinkedList::from(vec![2]),
+            LinkedList::from(vec![3, 3, 3]),
+            LinkedList::from(vec![1]),
+        ]);
+    }
+
+    #[test]
+    fn groups_runs_by_relation_between_neighbours() {
+        let under_test = LinkedList::from(vec![1, 2, 3, 2, 3, 1]);
+
+        assert_that(&under_test.chunk_by(|a, b| a < b).collect::<Vec<_>>()).is_equal_to(vec![
+            LinkedList::from(vec![1, 2, 3]),
+            LinkedList::from(vec![2, 3]),
+            LinkedList::from(vec![1]),
+        ]);
+    }
+
+    #[test]
+    fn yields_no_runs_for_empty_list() {
+        let under_test: LinkedList<i32> = LinkedList::new();
+
+        assert_that(&under_test.chunk_by(|a, b| a == b).next()).is_none();
+    }
+
+    #[test]
+    fn updates_list_length_while_extracting() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3, 4]);
+
+        under_test.extract_if(|v| *v % 2 == 1).for_each(drop);
+
+        assert_that(&under_test.len()).is_equal_to(2);
+    }
+
+    #[test]
+    fn tracks_lengths_of_detached_segments() {
+        let under_test = LinkedList::from(vec![1, 2, 3, 0, 4]);
+
+        let chunk_lens: Vec<_> = LinkedList::from(vec![1, 2, 3])
+            .into_chunks(2)
+            .map(|chunk| chunk.len())
+            .collect();
+        let segment_lens: Vec<_> = under_test
+            .split(|v| *v == 0)
+            .map(|segment| segment.len())
+            .collect();
+
+        assert_that(&chunk_lens).is_equal_to(vec![2, 1]);
+        assert_that(&segment_lens).is_equal_to(vec![3, 1]);
+    }
+}
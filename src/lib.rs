@@ -1,8 +1,76 @@
-#[derive(Debug, Eq, PartialEq)]
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{self, Write as _};
+use std::hash::Hash;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Index, IndexMut};
+use std::ptr::NonNull;
+
+pub use arena::{ArenaIntoIter, ArenaIter, ArenaIterMut, ArenaLinkedList};
+#[cfg(feature = "debug")]
+pub use debug::{NodeKind, Nodes};
+pub use encode::{Decode, Encode};
+pub use iter::{ChunkBy, Drain, ExtractIf, IntoChunks, IntoIter, Iter, IterMut, Split, Windows};
+pub use parse::ParseListError;
+pub use self_organizing::{Reorganization, SelfOrganizingList};
+pub use unrolled::{UnrolledIntoIter, UnrolledIter, UnrolledIterMut, UnrolledLinkedList};
+
+mod arena;
+#[cfg(feature = "debug")]
+mod debug;
+mod encode;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod iter;
+mod macros;
+mod parse;
+mod self_organizing;
+mod stream;
+mod text;
+mod unrolled;
+
 pub struct LinkedList<T> {
-    node: Node<T>,
+    head: Link<T>,
+    len: usize,
+    // points at the last node whenever the list is non-empty
+    tail: Link<T>,
+}
+
+// SAFETY: the list owns every node reachable from `head`, and `tail` only points
+// back into that chain, so moving the list to another thread moves nothing but
+// its values.
+unsafe impl<T: Send> Send for LinkedList<T> {}
+
+// SAFETY: a shared borrow of the list only hands out shared references to its
+// values and never relinks nodes, so sharing it is as safe as sharing a `&T`.
+unsafe impl<T: Sync> Sync for LinkedList<T> {}
+
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        // free one node at a time so dropping never recurses down the chain
+        self.clear();
+    }
+}
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        LinkedList::build(self.iter().cloned())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.truncate(source.len);
+
+        let mut remaining = source.iter();
+        for (target, value) in self.iter_mut().zip(&mut remaining) {
+            target.clone_from(value);
+        }
+
+        self.append(&mut LinkedList::build(remaining.cloned()));
+    }
 }
 
+macros::impl_list_traits!(LinkedList);
+
 impl<T> Default for LinkedList<T> {
     fn default() -> Self {
         LinkedList::new()
@@ -11,318 +79,3241 @@ impl<T> Default for LinkedList<T> {
 
 impl<T> LinkedList<T> {
     pub fn new() -> Self {
-        LinkedList { node: Node::Empty }
+        LinkedList {
+            head: None,
+            len: 0,
+            tail: None,
+        }
+    }
+
+    fn detach_from(&mut self, at: usize) -> LinkedList<T> {
+        if at == 0 {
+            return std::mem::take(self);
+        }
+
+        let Some(last_kept) = self.node_at(at - 1) else {
+            return LinkedList::new();
+        };
+        // SAFETY: `last_kept` is a live node of this list
+        let Some(head) = (unsafe { (*last_kept.as_ptr()).next.take() }) else {
+            return LinkedList::new();
+        };
+
+        // the detached part keeps the old tail, the kept part ends where it was cut
+        let detached = LinkedList {
+            head: Some(head),
+            len: self.len - at,
+            tail: self.tail,
+        };
+        self.tail = Some(last_kept);
+        self.len = at;
+
+        detached
+    }
+
+    fn node_at(&self, index: usize) -> Link<T> {
+        let mut link = self.head;
+        for _ in 0..index {
+            // SAFETY: every linked node stays allocated while the list owns it
+            link = unsafe { (*link?.as_ptr()).next };
+        }
+
+        link
+    }
+
+    // unlinks the first node without freeing it, handing ownership to the caller
+    fn pop_front_node(&mut self) -> Link<T> {
+        let node = self.head?;
+        // SAFETY: `node` is the live head of this list
+        self.head = unsafe { (*node.as_ptr()).next };
+        self.len -= 1;
+
+        if self.head.is_none() {
+            self.tail = None;
+        }
+
+        Some(node)
+    }
+
+    // SAFETY: callers must pass a node from `Node::alloc` that no list links to
+    unsafe fn push_node(&mut self, node: NonNull<Node<T>>) {
+        (*node.as_ptr()).next = None;
+        match self.tail {
+            Some(tail) => (*tail.as_ptr()).next = Some(node),
+            None => self.head = Some(node),
+        }
+
+        self.tail = Some(node);
+        self.len += 1;
     }
 
+    // Inherent functions win over trait impls, so `LinkedList::from(x)` always
+    // lands here and collects whatever `x` yields: `&[T]` gives a list of `&T`.
+    // The `From` impls below, such as the cloning `From<&[T]>`, are only reached
+    // through `.into()` or generic `From` bounds
     pub fn from<I: IntoIterator<Item = T>>(it: I) -> Self {
+        LinkedList::build(it)
+    }
+
+    pub fn from_fn<F: FnMut(usize) -> T>(n: usize, f: F) -> Self {
+        LinkedList::build((0..n).map(f))
+    }
+
+    pub fn successors<F: FnMut(&T) -> Option<T>>(first: Option<T>, succ: F) -> Self {
+        LinkedList::build(std::iter::successors(first, succ))
+    }
+
+    pub fn unfold<S, F: FnMut(&mut S) -> Option<T>>(mut state: S, mut f: F) -> Self {
+        LinkedList::build(std::iter::from_fn(|| f(&mut state)))
+    }
+
+    pub fn repeat(value: T, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        LinkedList::build(std::iter::repeat_n(value, n))
+    }
+
+    pub fn repeat_with<F: FnMut() -> T>(f: F, n: usize) -> Self {
+        LinkedList::build(std::iter::repeat_with(f).take(n))
+    }
+
+    fn build<I: IntoIterator<Item = T>>(it: I) -> Self {
         let mut list = LinkedList::new();
 
+        // push goes straight to the tail, so building stays a single pass
         it.into_iter().for_each(|v| list.push(v));
 
         list
     }
 
-    pub fn to_vec(mut self) -> Vec<T> {
-        let mut vec = vec![];
+    pub fn into_vec(mut self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len);
 
-        while let Some(value) = self.node.pop_front() {
+        while let Some(value) = self.pop_front() {
             vec.push(value);
         }
 
         vec
     }
 
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(self.len);
+        vec.extend(self.iter().cloned());
+
+        vec
+    }
+
+    pub fn into_sorted_vec(self) -> Vec<T>
+    where
+        T: Ord,
+    {
+        let mut vec = self.into_vec();
+        vec.sort();
+
+        vec
+    }
+
     pub fn push(&mut self, val: T) {
-        self.node.push(val);
+        // SAFETY: the node is freshly allocated and not linked anywhere yet
+        unsafe { self.push_node(Node::alloc(val, None)) };
     }
 
     pub fn push_front(&mut self, val: T) {
-        self.node.push_front(val);
+        let node = Node::alloc(val, self.head);
+
+        if self.tail.is_none() {
+            self.tail = Some(node);
+        }
+        self.head = Some(node);
+        self.len += 1;
     }
 
-    pub fn pop(&mut self) -> Option<T> {
-        self.node.pop()
+    pub fn insert(&mut self, index: usize, val: T) {
+        assert!(
+            index <= self.len,
+            "insertion index (is {index}) should be <= len (is {})",
+            self.len
+        );
+
+        match index {
+            0 => self.push_front(val),
+            _ if index == self.len => self.push(val),
+            _ => {
+                let prev = self.node_at(index - 1).unwrap();
+                // SAFETY: `prev` is a live node of this list that is not its tail
+                unsafe {
+                    let next = (*prev.as_ptr()).next;
+                    (*prev.as_ptr()).next = Some(Node::alloc(val, next));
+                }
+                self.len += 1;
+            }
+        }
     }
 
-    pub fn pop_front(&mut self) -> Option<T> {
-        self.node.pop_front()
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        let Some(tail) = self.tail else {
+            return std::mem::swap(self, other);
+        };
+        if other.is_empty() {
+            return;
+        }
+
+        // SAFETY: `tail` points at the last node of this list
+        unsafe { (*tail.as_ptr()).next = other.head.take() };
+        self.tail = other.tail.take();
+        self.len += std::mem::take(&mut other.len);
     }
 
-    pub fn size(&self) -> usize {
-        self.node.size()
+    pub fn prepend(&mut self, mut other: LinkedList<T>) {
+        other.append(self);
+        *self = other;
     }
-}
 
-#[derive(Debug, Eq, PartialEq)]
-enum Node<T> {
-    Empty,
-    Tail { value: T },
-    Parent { value: T, next: Box<Node<T>> },
-}
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, it: I) {
+        self.prepend(LinkedList::build(it));
+    }
 
-impl<T> Default for Node<T> {
-    fn default() -> Self {
-        Node::Empty
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        assert!(at <= self.len, "cannot split off at a nonexistent index");
+
+        self.detach_from(at)
     }
-}
 
-impl<T> Node<T> {
-    fn push(&mut self, val: T) {
-        match self {
-            Node::Empty => *self = Node::Tail { value: val },
-            Node::Tail { .. } => self.to_parent(val),
-            Node::Parent { next, .. } => next.push(val),
-        };
+    pub fn split_at(mut self, at: usize) -> (LinkedList<T>, LinkedList<T>) {
+        let back = self.split_off(at);
+
+        (self, back)
     }
 
-    fn push_front(&mut self, val: T) {
-        if self.is_empty() {
-            return self.push(val);
-        }
+    pub fn span<P: FnMut(&T) -> bool>(self, mut pred: P) -> (LinkedList<T>, LinkedList<T>) {
+        let at = self.position(|v| !pred(v)).unwrap_or(self.len);
+
+        self.split_at(at)
+    }
 
-        let new_child = std::mem::take(self);
+    pub fn split_first(mut self) -> Option<(T, LinkedList<T>)> {
+        let first = self.pop_front()?;
 
-        *self = Node::Parent {
-            value: val,
-            next: Box::new(new_child),
-        };
+        Some((first, self))
     }
 
-    fn pop(&mut self) -> Option<T> {
-        match self {
-            Node::Empty => None,
-            Node::Tail { .. } => Some(self.to_empty()),
-            Node::Parent { next, .. } => {
-                if next.is_tail() {
-                    Some(self.to_tail())
-                } else {
-                    next.pop()
-                }
-            }
-        }
+    pub fn split_last(mut self) -> Option<(LinkedList<T>, T)> {
+        let last = self.pop()?;
+
+        Some((self, last))
+    }
+
+    pub fn insert_sorted(&mut self, value: T)
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(value, T::cmp);
     }
 
-    fn pop_front(&mut self) -> Option<T> {
-        match self {
-            Node::Empty => None,
-            Node::Tail { .. } => Some(self.to_empty()),
-            Node::Parent { next, .. } => {
-                let new_self = std::mem::take(next);
+    pub fn insert_sorted_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, value: T, mut key: F) {
+        self.insert_sorted_by(value, |a, b| key(a).cmp(&key(b)));
+    }
 
-                let mut old_self = new_self;
-                std::mem::swap(self, &mut old_self);
+    pub fn insert_sorted_by<F: FnMut(&T, &T) -> Ordering>(&mut self, value: T, mut compare: F) {
+        let index = self
+            .position(|v| compare(v, &value) == Ordering::Greater)
+            .unwrap_or(self.len);
 
-                Some(old_self.value())
-            }
-        }
+        self.insert(index, value);
     }
 
-    fn to_parent(&mut self, child_value: T) {
-        *self = Node::Parent {
-            value: self.to_empty(),
-            next: Box::new(Node::Tail { value: child_value }),
-        };
+    pub fn front(&self) -> Option<&T> {
+        // SAFETY: the list owns its head, and the borrow of the list keeps it
+        // from being relinked or freed while the reference is alive
+        self.head.map(|node| unsafe { &(*node.as_ptr()).value })
     }
 
-    fn to_tail(&mut self) -> T {
-        let popped_val = self.next().unwrap().to_empty();
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: as for `front`, with the unique borrow of the list making the
+        // reference unique too
+        self.head.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
 
-        *self = Node::Tail {
-            value: self.to_empty(),
-        };
+    pub fn back(&self) -> Option<&T> {
+        // SAFETY: `tail` points at the last node owned by this list, which the
+        // borrow of the list keeps alive
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
 
-        popped_val
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: as for `back`, with the unique borrow of the list making the
+        // reference unique too
+        self.tail.map(|node| unsafe { &mut (*node.as_ptr()).value })
     }
 
-    fn to_empty(&mut self) -> T {
-        std::mem::take(self).value()
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
     }
 
-    fn is_tail(&self) -> bool {
-        matches!(self, Node::Tail { .. })
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        // SAFETY: `node_at` only returns nodes owned by this list, which the
+        // unique borrow of the list keeps alive and unaliased
+        self.node_at(index)
+            .map(|node| unsafe { &mut (*node.as_ptr()).value })
     }
 
-    fn is_empty(&self) -> bool {
-        matches!(self, Node::Empty)
+    pub fn replace(&mut self, index: usize, value: T) -> Option<T> {
+        self.get_mut(index)
+            .map(|slot| std::mem::replace(slot, value))
     }
 
-    fn value(self) -> T {
-        match self {
-            Node::Empty => panic!("expected value node"),
-            Node::Tail { value } => value,
-            Node::Parent { value, .. } => value,
+    pub fn move_to_front(&mut self, index: usize) {
+        assert!(
+            index < self.len,
+            "index out of bounds: the len is {} but the index is {index}",
+            self.len
+        );
+
+        if index == 0 {
+            return;
+        }
+
+        let prev = self.node_at(index - 1).unwrap();
+        // SAFETY: `prev` and the node after it are distinct live nodes of this list
+        unsafe {
+            let node = (*prev.as_ptr()).next.unwrap();
+            (*prev.as_ptr()).next = (*node.as_ptr()).next;
+
+            if (*prev.as_ptr()).next.is_none() {
+                self.tail = Some(prev);
+            }
+            (*node.as_ptr()).next = self.head;
+            self.head = Some(node);
         }
     }
 
-    fn next(&mut self) -> Option<&mut Self> {
-        if let Node::Parent { next, .. } = self {
-            return Some(next);
+    pub fn swap(&mut self, i: usize, j: usize) {
+        let len = self.len;
+        for index in [i, j] {
+            assert!(
+                index < len,
+                "index out of bounds: the len is {len} but the index is {index}"
+            );
         }
-        None
+
+        if i == j {
+            return;
+        }
+
+        let (low, high) = (i.min(j), i.max(j));
+        let mut iter = self.iter_mut();
+        let a = iter.nth(low).unwrap();
+        let b = iter.nth(high - low - 1).unwrap();
+
+        std::mem::swap(a, b);
+    }
+
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|v| v == value)
+    }
+
+    pub fn starts_with<'a, I>(&self, prefix: I) -> bool
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: PartialEq + 'a,
+    {
+        let mut iter = self.iter();
+
+        prefix.into_iter().all(|p| iter.next() == Some(p))
+    }
+
+    pub fn ends_with<'a, I>(&self, suffix: I) -> bool
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: PartialEq + 'a,
+    {
+        let suffix: Vec<_> = suffix.into_iter().collect();
+
+        suffix.len() <= self.len && self.iter().skip(self.len - suffix.len()).eq(suffix)
     }
 
-    fn size(&self) -> usize {
-        match self {
-            Node::Empty => 0,
-            Node::Tail { .. } => 1,
-            Node::Parent { next, .. } => 1 + next.size(),
+    pub fn strip_prefix<'a, I>(&mut self, prefix: I) -> bool
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: PartialEq + 'a,
+    {
+        let prefix: Vec<_> = prefix.into_iter().collect();
+        if !self.starts_with(prefix.iter().copied()) {
+            return false;
         }
+
+        let rest = self.detach_from(prefix.len());
+        *self = rest;
+
+        true
     }
-}
 
-impl<V> FromIterator<V> for LinkedList<V> {
-    fn from_iter<T: IntoIterator<Item = V>>(iter: T) -> Self {
-        LinkedList::from(iter)
+    pub fn strip_suffix<'a, I>(&mut self, suffix: I) -> bool
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: PartialEq + 'a,
+    {
+        let suffix: Vec<_> = suffix.into_iter().collect();
+        if !self.ends_with(suffix.iter().copied()) {
+            return false;
+        }
+
+        self.truncate(self.len - suffix.len());
+
+        true
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use speculoos::prelude::*;
+    pub fn find_subsequence<'a, I>(&self, needle: I) -> Option<usize>
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: PartialEq + 'a,
+    {
+        let needle: Vec<_> = needle.into_iter().collect();
+        let candidates = (self.len + 1).checked_sub(needle.len())?;
+        let mut start = self.iter();
+
+        for index in 0..candidates {
+            if start.clone().take(needle.len()).eq(needle.iter().copied()) {
+                return Some(index);
+            }
+            start.next();
+        }
 
-    use super::*;
+        None
+    }
 
-    #[test]
-    fn converts_iterator_into_linked_list() {
-        assert_that(&LinkedList::from(vec![1, 2, 3].into_iter()).to_vec())
-            .is_equal_to(vec![1, 2, 3]);
+    pub fn position<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+        self.iter().position(pred)
     }
 
-    #[test]
-    fn converts_iterator_trait_into_linked_list() {
-        assert_that(&LinkedList::from_iter(vec![1, 2, 3].into_iter()).to_vec())
-            .is_equal_to(vec![1, 2, 3]);
+    pub fn find<P: FnMut(&T) -> bool>(&self, mut pred: P) -> Option<&T> {
+        self.iter().find(|v| pred(v))
     }
 
-    #[test]
-    fn collects_from_iterator() {
-        assert_that(&vec![1, 2].into_iter().collect()).is_equal_to(LinkedList::from(vec![1, 2]));
+    pub fn search_sorted(&self, value: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.search_sorted_by(|v| v.cmp(value))
     }
 
-    #[test]
-    fn converts_vec_into_linked_list() {
-        assert_that(&LinkedList::from(vec![1, 2, 3]).to_vec()).is_equal_to(vec![1, 2, 3]);
+    pub fn search_sorted_by_key<K: Ord, F: FnMut(&T) -> K>(
+        &self,
+        key: &K,
+        mut f: F,
+    ) -> Result<usize, usize> {
+        self.search_sorted_by(|v| f(v).cmp(key))
     }
 
-    #[test]
-    fn pushes_to_empty_list() {
-        let mut under_test = LinkedList::new();
+    pub fn search_sorted_by<F: FnMut(&T) -> Ordering>(&self, mut f: F) -> Result<usize, usize> {
+        for (index, value) in self.iter().enumerate() {
+            match f(value) {
+                Ordering::Less => continue,
+                Ordering::Equal => return Ok(index),
+                Ordering::Greater => return Err(index),
+            }
+        }
 
-        assert_that(&under_test.size()).is_equal_to(0);
+        Err(self.len)
+    }
 
-        under_test.push(1);
+    pub fn counts(&self) -> HashMap<T, usize>
+    where
+        T: Eq + Hash + Clone,
+    {
+        let mut counts = HashMap::new();
+        for value in self {
+            *counts.entry(value.clone()).or_insert(0) += 1;
+        }
 
-        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1]));
-        assert_that(&under_test.size()).is_equal_to(1);
+        counts
     }
 
-    #[test]
-    fn pushes_to_singleton_list() {
-        let mut under_test = LinkedList::from(vec![1]);
+    pub fn into_counts(self) -> HashMap<T, usize>
+    where
+        T: Eq + Hash,
+    {
+        let mut counts = HashMap::new();
+        for value in self {
+            *counts.entry(value).or_insert(0) += 1;
+        }
 
-        assert_that(&under_test.size()).is_equal_to(1);
+        counts
+    }
 
-        under_test.push(2);
+    pub fn join(&self, separator: &str) -> String
+    where
+        T: fmt::Display,
+    {
+        let mut joined = String::new();
 
-        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2]));
-        assert_that(&under_test.size()).is_equal_to(2);
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                joined.push_str(separator);
+            }
+            write!(joined, "{value}").unwrap();
+        }
+
+        joined
     }
 
-    #[test]
-    fn pushes_to_multi_list() {
-        let mut under_test = LinkedList::from(vec![1, 2]);
+    pub fn pop(&mut self) -> Option<T> {
+        let at = self.len.checked_sub(1)?;
 
-        assert_that(&under_test.size()).is_equal_to(2);
+        self.detach_from(at).pop_front()
+    }
 
-        under_test.push(3);
+    pub fn pop_front(&mut self) -> Option<T> {
+        let node = self.pop_front_node()?;
 
-        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3]));
-        assert_that(&under_test.size()).is_equal_to(3);
+        // SAFETY: the node was just unlinked, so nothing else refers to it
+        Some(unsafe { Node::free(node) }.value)
     }
 
-    #[test]
-    fn pushes_to_front_of_empty_list() {
-        let mut under_test = LinkedList::new();
+    pub fn pop_if<P: FnOnce(&mut T) -> bool>(&mut self, pred: P) -> Option<T> {
+        if pred(self.back_mut()?) {
+            self.pop()
+        } else {
+            None
+        }
+    }
 
-        assert_that(&under_test.size()).is_equal_to(0);
+    pub fn pop_front_if<P: FnOnce(&mut T) -> bool>(&mut self, pred: P) -> Option<T> {
+        if pred(self.front_mut()?) {
+            self.pop_front()
+        } else {
+            None
+        }
+    }
 
-        under_test.push_front(1);
+    pub fn len(&self) -> usize {
+        self.len
+    }
 
-        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1]));
-        assert_that(&under_test.size()).is_equal_to(1);
+    pub fn size(&self) -> usize {
+        self.len
     }
 
-    #[test]
-    fn pushes_to_front_of_singleton_list() {
-        let mut under_test = LinkedList::from(vec![1]);
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
 
-        assert_that(&under_test.size()).is_equal_to(1);
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
 
-        under_test.push_front(2);
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(n <= self.len, "cannot rotate by more than the list length");
 
-        assert_that(&under_test).is_equal_to(LinkedList::from(vec![2, 1]));
-        assert_that(&under_test.size()).is_equal_to(2);
+        let mut rotated = self.detach_from(n);
+        rotated.append(self);
+        *self = rotated;
     }
 
-    #[test]
-    fn pushes_to_front_of_multi_list() {
-        let mut under_test = LinkedList::from(vec![1, 2]);
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(n <= self.len, "cannot rotate by more than the list length");
 
-        assert_that(&under_test.size()).is_equal_to(2);
+        self.rotate_left(self.len - n);
+    }
 
-        under_test.push_front(3);
+    pub fn truncate(&mut self, len: usize) {
+        self.detach_from(len).clear();
+    }
 
-        assert_that(&under_test).is_equal_to(LinkedList::from(vec![3, 1, 2]));
-        assert_that(&under_test.size()).is_equal_to(3);
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) {
+        if new_len <= self.len {
+            return self.truncate(new_len);
+        }
+
+        self.extend((self.len..new_len).map(|_| f()));
     }
 
-    #[test]
-    fn returns_empty_when_empty_list_is_popped() {
-        let mut under_test: LinkedList<i32> = LinkedList::new();
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.resize_with(new_len, || value.clone());
+    }
 
-        assert_that(&under_test.pop()).is_none();
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.merge_sort_by(&mut T::cmp);
     }
 
-    #[test]
-    fn returns_value_when_singleton_list_is_popped() {
-        let mut under_test = LinkedList::from(vec![1]);
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        self.merge_sort_by(&mut compare);
+    }
 
-        assert_that(&under_test.size()).is_equal_to(1);
-        assert_that(&under_test.pop()).contains(1);
-        assert_that(&under_test.size()).is_equal_to(0);
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        self.merge_sort_by(&mut |a, b| key(a).cmp(&key(b)));
     }
 
-    #[test]
-    fn returns_value_when_multi_list_is_popped() {
-        let mut under_test = LinkedList::from(vec![1, 2]);
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
 
-        assert_that(&under_test.size()).is_equal_to(2);
-        assert_that(&under_test.pop()).contains(2);
-        assert_that(&under_test.size()).is_equal_to(1);
+    pub fn is_sorted_by<F: FnMut(&T, &T) -> bool>(&self, mut compare: F) -> bool {
+        self.iter()
+            .zip(self.iter().skip(1))
+            .all(|(a, b)| compare(a, b))
     }
 
-    #[test]
-    fn returns_empty_when_empty_list_is_front_popped() {
-        let mut under_test: LinkedList<i32> = LinkedList::new();
+    pub fn is_sorted_by_key<K: PartialOrd, F: FnMut(&T) -> K>(&self, mut key: F) -> bool {
+        self.is_sorted_by(|a, b| key(a) <= key(b))
+    }
 
-        assert_that(&under_test.pop_front()).is_none();
+    fn merge_sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: &mut F) {
+        // bottom-up merge sort: runs[i] is either empty or a sorted run of
+        // 2^i elements taken from before everything in runs[..i]
+        let mut runs: Vec<LinkedList<T>> = Vec::new();
+
+        while !self.is_empty() {
+            let rest = self.detach_from(1);
+            let mut carry = std::mem::replace(self, rest);
+
+            let mut rank = 0;
+            while rank < runs.len() && !runs[rank].is_empty() {
+                carry = LinkedList::merge(std::mem::take(&mut runs[rank]), carry, compare);
+                rank += 1;
+            }
+
+            if rank == runs.len() {
+                runs.push(carry);
+            } else {
+                runs[rank] = carry;
+            }
+        }
+
+        for run in runs {
+            let merged = std::mem::take(self);
+            *self = LinkedList::merge(run, merged, compare);
+        }
     }
 
-    #[test]
-    fn returns_value_when_singleton_list_is_front_popped() {
-        let mut under_test = LinkedList::from(vec![1]);
+    fn merge<F: FnMut(&T, &T) -> Ordering>(
+        a: LinkedList<T>,
+        b: LinkedList<T>,
+        compare: &mut F,
+    ) -> LinkedList<T> {
+        LinkedList::weave(a, b, |x, y| compare(y, x) == Ordering::Less)
+    }
 
-        assert_that(&under_test.size()).is_equal_to(1);
-        assert_that(&under_test.pop_front()).contains(1);
-        assert_that(&under_test.size()).is_equal_to(0);
+    fn weave<F: FnMut(&T, &T) -> bool>(
+        mut a: LinkedList<T>,
+        mut b: LinkedList<T>,
+        mut take_b: F,
+    ) -> LinkedList<T> {
+        let mut woven = LinkedList::new();
+
+        loop {
+            let source = match (a.front(), b.front()) {
+                (Some(x), Some(y)) if take_b(x, y) => &mut b,
+                (Some(_), Some(_)) => &mut a,
+                _ => break,
+            };
+
+            // whole nodes are relinked, so the woven chain reuses the existing
+            // allocations
+            let node = source.pop_front_node().unwrap();
+            // SAFETY: the node was just unlinked from `source`
+            unsafe { woven.push_node(node) };
+        }
+        woven.append(&mut a);
+        woven.append(&mut b);
+
+        woven
     }
 
-    #[test]
-    fn returns_value_when_multi_list_is_front_popped() {
-        let mut under_test = LinkedList::from(vec![1, 2]);
+    pub fn reverse(&mut self) {
+        let mut remaining = self.head.take();
+        self.tail = remaining;
 
-        assert_that(&under_test.size()).is_equal_to(2);
-        assert_that(&under_test.pop_front()).contains(1);
+        while let Some(node) = remaining {
+            // SAFETY: `node` is a live node of this list being relinked in front
+            unsafe {
+                remaining = (*node.as_ptr()).next;
+                (*node.as_ptr()).next = self.head;
+            }
+            self.head = Some(node);
+        }
+    }
+
+    fn head_node(&self) -> Option<&Node<T>> {
+        // SAFETY: the list owns its head, which therefore lives at least as long
+        // as the borrow of the list
+        self.head.map(|node| unsafe { &*node.as_ptr() })
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.head_node(), self.len)
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        // SAFETY: the unique borrow of the list extends to the nodes it owns
+        let head = self.head.map(|node| unsafe { &mut *node.as_ptr() });
+
+        IterMut::new(head, self.len)
+    }
+
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> LinkedList<U> {
+        LinkedList::build(self.into_iter().map(f))
+    }
+
+    pub fn filter<P: FnMut(&T) -> bool>(mut self, pred: P) -> LinkedList<T> {
+        self.retain(pred);
+
+        self
+    }
+
+    pub fn filter_map<U, F: FnMut(T) -> Option<U>>(self, f: F) -> LinkedList<U> {
+        LinkedList::build(self.into_iter().filter_map(f))
+    }
+
+    pub fn flat_map<U, F: FnMut(T) -> LinkedList<U>>(self, f: F) -> LinkedList<U> {
+        self.map(f).flatten()
+    }
+
+    pub fn scan<S, U, F: FnMut(&mut S, T) -> Option<U>>(self, init: S, f: F) -> LinkedList<U> {
+        LinkedList::build(self.into_iter().scan(init, f))
+    }
+
+    pub fn group_by_key<K: Eq + Hash, F: FnMut(&T) -> K>(
+        mut self,
+        mut f: F,
+    ) -> HashMap<K, LinkedList<T>> {
+        let mut groups: HashMap<K, LinkedList<T>> = HashMap::new();
+
+        // the key is computed before unlinking, so a panicking `f` leaves the
+        // node owned by `self`
+        while let Some(value) = self.front() {
+            let key = f(value);
+            let node = self.pop_front_node().unwrap();
+
+            // SAFETY: the node was just unlinked from `self`
+            unsafe { groups.entry(key).or_default().push_node(node) };
+        }
+
+        groups
+    }
+
+    pub fn zip<U>(self, other: LinkedList<U>) -> LinkedList<(T, U)> {
+        LinkedList::build(self.into_iter().zip(other))
+    }
+
+    pub fn zip_with<U, V, F: FnMut(T, U) -> V>(
+        self,
+        other: LinkedList<U>,
+        mut f: F,
+    ) -> LinkedList<V> {
+        LinkedList::build(self.into_iter().zip(other).map(|(a, b)| f(a, b)))
+    }
+
+    pub fn interleave(self, other: LinkedList<T>) -> LinkedList<T> {
+        let mut from_other = false;
+
+        LinkedList::weave(self, other, |_, _| {
+            from_other = !from_other;
+            !from_other
+        })
+    }
+
+    pub fn chunk_by<F: FnMut(&T, &T) -> bool>(self, pred: F) -> ChunkBy<T, F> {
+        ChunkBy::new(self, pred)
+    }
+
+    pub fn split<F: FnMut(&T) -> bool>(self, pred: F) -> Split<T, F> {
+        Split::new(self, pred)
+    }
+
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        assert!(size != 0, "window size must be non-zero");
+
+        Windows::new(self.iter(), size)
+    }
+
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain::new(self)
+    }
+
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, pred: F) -> ExtractIf<'_, T, F> {
+        ExtractIf::new(self, pred)
+    }
+
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.extract_if(|v| !f(v)).for_each(drop);
+    }
+
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        let Some(mut node) = self.head else {
+            return;
+        };
+
+        // SAFETY: `node` and `next` are distinct live nodes of this list, and
+        // `next` is freed only after it has been unlinked
+        while let Some(next) = unsafe { (*node.as_ptr()).next } {
+            let duplicate =
+                unsafe { same_bucket(&mut (*next.as_ptr()).value, &mut (*node.as_ptr()).value) };
+
+            if duplicate {
+                unsafe {
+                    (*node.as_ptr()).next = (*next.as_ptr()).next;
+                    drop(Node::free(next));
+                }
+                self.len -= 1;
+            } else {
+                node = next;
+            }
+        }
+
+        self.tail = Some(node);
+    }
+
+    pub fn remove_first(&mut self, value: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        self.extract_if(|v| v == value).next()
+    }
+
+    pub fn remove_all(&mut self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.extract_if(|v| v == value).count()
+    }
+
+    pub fn unique(&mut self)
+    where
+        T: Eq + Hash,
+    {
+        let mut seen = HashSet::with_capacity(self.len);
+        let mut first_occurrences = self
+            .iter()
+            .map(|v| seen.insert(v))
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        self.retain(|_| first_occurrences.next().unwrap());
+    }
+
+    pub fn into_chunks(self, chunk_size: usize) -> IntoChunks<T> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+
+        IntoChunks::new(self, chunk_size)
+    }
+}
+
+impl<T> LinkedList<LinkedList<T>> {
+    pub fn flatten(self) -> LinkedList<T> {
+        let mut flattened = LinkedList::new();
+
+        for mut inner in self {
+            flattened.append(&mut inner);
+        }
+
+        flattened
+    }
+
+    pub fn transpose(self) -> LinkedList<LinkedList<T>> {
+        let mut rows: Vec<_> = self.into_iter().collect();
+        let width = rows.iter().map(LinkedList::len).min().unwrap_or(0);
+
+        LinkedList::from_fn(width, |_| {
+            LinkedList::build(rows.iter_mut().map(|row| row.pop_front().unwrap()))
+        })
+    }
+
+    pub fn transpose_padded(self) -> LinkedList<LinkedList<Option<T>>> {
+        let mut rows: Vec<_> = self.into_iter().collect();
+        let width = rows.iter().map(LinkedList::len).max().unwrap_or(0);
+
+        LinkedList::from_fn(width, |_| {
+            LinkedList::build(rows.iter_mut().map(LinkedList::pop_front))
+        })
+    }
+}
+
+impl<S: AsRef<str>> LinkedList<S> {
+    pub fn join_str(&self, separator: &str) -> String {
+        let capacity = self.iter().map(|s| s.as_ref().len()).sum::<usize>()
+            + separator.len() * self.len.saturating_sub(1);
+        let mut joined = String::with_capacity(capacity);
+
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                joined.push_str(separator);
+            }
+            joined.push_str(value.as_ref());
+        }
+
+        joined
+    }
+}
+
+impl<A, B> LinkedList<(A, B)> {
+    pub fn unzip(self) -> (LinkedList<A>, LinkedList<B>) {
+        let mut left = LinkedList::new();
+        let mut right = LinkedList::new();
+
+        for (a, b) in self {
+            left.push(a);
+            right.push(b);
+        }
+
+        (left, right)
+    }
+}
+
+type Link<T> = Option<NonNull<Node<T>>>;
+
+// each node owns the node its `next` points at, exactly as a `Box` would
+struct Node<T> {
+    value: T,
+    next: Link<T>,
+}
+
+// SAFETY: a node uniquely owns the rest of its chain, so sending it sends
+// nothing but values of type `T`.
+unsafe impl<T: Send> Send for Node<T> {}
+
+// SAFETY: a shared node only gives out shared access to its value and its
+// successors, so sharing it is as safe as sharing a `&T`.
+unsafe impl<T: Sync> Sync for Node<T> {}
+
+impl<T> Node<T> {
+    // nodes are leaked into raw pointers once, so every link and `tail` share
+    // the same provenance and no `Box` is ever reborrowed over a node a raw
+    // pointer still refers to. References made from these pointers, including
+    // the `&Node`/`&mut Node` that `Iter` and `IterMut` hold for their whole
+    // lifetime, are tied to a borrow of the list, so `tail` is only used to
+    // write to a node after those borrows end
+    fn alloc(value: T, next: Link<T>) -> NonNull<Node<T>> {
+        NonNull::from(Box::leak(Box::new(Node { value, next })))
+    }
+
+    // SAFETY: callers must pass a node from `alloc` that no list links to and
+    // that is never used again
+    unsafe fn free(node: NonNull<Node<T>>) -> Node<T> {
+        *Box::from_raw(node.as_ptr())
+    }
+
+    fn next(&self) -> Option<&Node<T>> {
+        // SAFETY: a node owns its successor, which therefore lives at least as
+        // long as the borrow of the node
+        self.next.map(|next| unsafe { &*next.as_ptr() })
+    }
+}
+
+impl<T> Index<usize> for LinkedList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {index}",
+                self.len
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for LinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!("index out of bounds: the len is {len} but the index is {index}"),
+        }
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<V> FromIterator<V> for LinkedList<V> {
+    fn from_iter<T: IntoIterator<Item = V>>(iter: T) -> Self {
+        LinkedList::build(iter)
+    }
+}
+
+impl<T> From<Vec<T>> for LinkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        LinkedList::build(vec)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+    fn from(array: [T; N]) -> Self {
+        LinkedList::build(array)
+    }
+}
+
+impl<T: Clone> From<&[T]> for LinkedList<T> {
+    fn from(slice: &[T]) -> Self {
+        LinkedList::build(slice.iter().cloned())
+    }
+}
+
+impl<T> From<LinkedList<T>> for Vec<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_vec()
+    }
+}
+
+impl<T> From<LinkedList<T>> for VecDeque<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        VecDeque::from(list.into_vec())
+    }
+}
+
+impl<T> From<LinkedList<T>> for Box<[T]> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_vec().into_boxed_slice()
+    }
+}
+
+impl<T> From<std::collections::LinkedList<T>> for LinkedList<T> {
+    fn from(list: std::collections::LinkedList<T>) -> Self {
+        LinkedList::build(list)
+    }
+}
+
+impl<T> From<LinkedList<T>> for std::collections::LinkedList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for LinkedList<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.len == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for LinkedList<T> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        *self == *other.as_slice()
+    }
+}
+
+impl<T> Add for LinkedList<T> {
+    type Output = LinkedList<T>;
+
+    fn add(mut self, mut rhs: LinkedList<T>) -> Self::Output {
+        self.append(&mut rhs);
+        self
+    }
+}
+
+impl<T> AddAssign for LinkedList<T> {
+    fn add_assign(&mut self, mut rhs: LinkedList<T>) {
+        self.append(&mut rhs);
+    }
+}
+
+impl<T> Sum for LinkedList<T> {
+    fn sum<I: Iterator<Item = LinkedList<T>>>(iter: I) -> Self {
+        LinkedList::build(iter).flatten()
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.append(&mut LinkedList::build(iter));
+    }
+}
+
+impl<'a, T: 'a + Copy> Extend<&'a T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::Hasher;
+
+    use speculoos::prelude::*;
+
+    use super::*;
+
+    #[test]
+    #[allow(clippy::useless_conversion)]
+    fn converts_iterator_into_linked_list() {
+        assert_that(&LinkedList::from(vec![1, 2, 3].into_iter()).to_vec())
+            .is_equal_to(vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[allow(clippy::useless_conversion)]
+    fn converts_iterator_trait_into_linked_list() {
+        assert_that(&LinkedList::from_iter(vec![1, 2, 3].into_iter()).to_vec())
+            .is_equal_to(vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn collects_from_iterator() {
+        assert_that(&vec![1, 2].into_iter().collect()).is_equal_to(LinkedList::from(vec![1, 2]));
+    }
+
+    #[test]
+    fn converts_vec_into_linked_list() {
+        assert_that(&LinkedList::from(vec![1, 2, 3]).to_vec()).is_equal_to(vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn pushes_to_empty_list() {
+        let mut under_test = LinkedList::new();
+
+        assert_that(&under_test.size()).is_equal_to(0);
+
+        under_test.push(1);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1]));
+        assert_that(&under_test.size()).is_equal_to(1);
+    }
+
+    #[test]
+    fn pushes_to_singleton_list() {
+        let mut under_test = LinkedList::from(vec![1]);
+
+        assert_that(&under_test.size()).is_equal_to(1);
+
+        under_test.push(2);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2]));
+        assert_that(&under_test.size()).is_equal_to(2);
+    }
+
+    #[test]
+    fn pushes_to_multi_list() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        assert_that(&under_test.size()).is_equal_to(2);
+
+        under_test.push(3);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+        assert_that(&under_test.size()).is_equal_to(3);
+    }
+
+    #[test]
+    fn pushes_to_front_of_empty_list() {
+        let mut under_test = LinkedList::new();
+
+        assert_that(&under_test.size()).is_equal_to(0);
+
+        under_test.push_front(1);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1]));
         assert_that(&under_test.size()).is_equal_to(1);
     }
+
+    #[test]
+    fn pushes_to_front_of_singleton_list() {
+        let mut under_test = LinkedList::from(vec![1]);
+
+        assert_that(&under_test.size()).is_equal_to(1);
+
+        under_test.push_front(2);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![2, 1]));
+        assert_that(&under_test.size()).is_equal_to(2);
+    }
+
+    #[test]
+    fn pushes_to_front_of_multi_list() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        assert_that(&under_test.size()).is_equal_to(2);
+
+        under_test.push_front(3);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![3, 1, 2]));
+        assert_that(&under_test.size()).is_equal_to(3);
+    }
+
+    #[test]
+    fn returns_empty_when_empty_list_is_popped() {
+        let mut under_test: LinkedList<i32> = LinkedList::new();
+
+        assert_that(&under_test.pop()).is_none();
+    }
+
+    #[test]
+    fn returns_value_when_singleton_list_is_popped() {
+        let mut under_test = LinkedList::from(vec![1]);
+
+        assert_that(&under_test.size()).is_equal_to(1);
+        assert_that(&under_test.pop()).contains(1);
+        assert_that(&under_test.size()).is_equal_to(0);
+    }
+
+    #[test]
+    fn returns_value_when_multi_list_is_popped() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        assert_that(&under_test.size()).is_equal_to(2);
+        assert_that(&under_test.pop()).contains(2);
+        assert_that(&under_test.size()).is_equal_to(1);
+    }
+
+    #[test]
+    fn returns_empty_when_empty_list_is_front_popped() {
+        let mut under_test: LinkedList<i32> = LinkedList::new();
+
+        assert_that(&under_test.pop_front()).is_none();
+    }
+
+    #[test]
+    fn returns_value_when_singleton_list_is_front_popped() {
+        let mut under_test = LinkedList::from(vec![1]);
+
+        assert_that(&under_test.size()).is_equal_to(1);
+        assert_that(&under_test.pop_front()).contains(1);
+        assert_that(&under_test.size()).is_equal_to(0);
+    }
+
+    #[test]
+    fn returns_value_when_multi_list_is_front_popped() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        assert_that(&under_test.size()).is_equal_to(2);
+        assert_that(&under_test.pop_front()).contains(1);
+        assert_that(&under_test.size()).is_equal_to(1);
+    }
+
+    #[test]
+    fn inserts_into_empty_list() {
+        let mut under_test = LinkedList::new();
+
+        under_test.insert(0, 1);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1]));
+    }
+
+    #[test]
+    fn inserts_at_front_middle_and_back() {
+        let mut under_test = LinkedList::from(vec![2, 4]);
+
+        under_test.insert(0, 1);
+        under_test.insert(2, 3);
+        under_test.insert(4, 5);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3, 4, 5]));
+        assert_that(&under_test.size()).is_equal_to(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 3) should be <= len (is 2)")]
+    fn panics_when_inserting_out_of_bounds() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        under_test.insert(3, 3);
+    }
+
+    #[test]
+    fn gets_element_at_index() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.get(0)).contains(&1);
+        assert_that(&under_test.get(2)).contains(&3);
+    }
+
+    #[test]
+    fn returns_none_when_getting_out_of_bounds() {
+        let under_test = LinkedList::from(vec![1]);
+
+        assert_that(&under_test.get(1)).is_none();
+        assert_that(&LinkedList::<i32>::new().get(0)).is_none();
+    }
+
+    #[test]
+    fn updates_element_through_mutable_get() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        *under_test.get_mut(1).unwrap() = 5;
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 5, 3]));
+        assert_that(&under_test.get_mut(3)).is_none();
+    }
+
+    #[test]
+    fn indexes_into_list() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test[1]).is_equal_to(2);
+    }
+
+    #[test]
+    fn updates_element_through_index() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        under_test[2] += 1;
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 4]));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+    fn panics_when_indexing_out_of_bounds() {
+        let under_test = LinkedList::from(vec![1, 2]);
+
+        let _ = under_test[2];
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 0 but the index is 0")]
+    fn panics_when_mutably_indexing_out_of_bounds() {
+        let mut under_test: LinkedList<i32> = LinkedList::new();
+
+        under_test[0] = 1;
+    }
+
+    #[test]
+    fn peeks_at_front_of_list() {
+        let under_test = LinkedList::from(vec![1, 2]);
+
+        assert_that(&under_test.front()).contains(&1);
+        assert_that(&under_test.size()).is_equal_to(2);
+    }
+
+    #[test]
+    fn returns_none_when_peeking_at_front_of_empty_list() {
+        let mut under_test: LinkedList<i32> = LinkedList::new();
+
+        assert_that(&under_test.front()).is_none();
+        assert_that(&under_test.front_mut()).is_none();
+    }
+
+    #[test]
+    fn updates_front_of_list() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        *under_test.front_mut().unwrap() = 3;
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![3, 2]));
+    }
+
+    #[test]
+    fn peeks_at_back_of_list() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.back()).contains(&3);
+        assert_that(&under_test.size()).is_equal_to(3);
+    }
+
+    #[test]
+    fn returns_none_when_peeking_at_back_of_empty_list() {
+        let mut under_test: LinkedList<i32> = LinkedList::new();
+
+        assert_that(&under_test.back()).is_none();
+        assert_that(&under_test.back_mut()).is_none();
+    }
+
+    #[test]
+    fn updates_back_of_list() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        *under_test.back_mut().unwrap() = 3;
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 3]));
+    }
+
+    #[test]
+    fn contains_element_in_list() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.contains(&1)).is_true();
+        assert_that(&under_test.contains(&3)).is_true();
+    }
+
+    #[test]
+    fn does_not_contain_missing_element() {
+        assert_that(&LinkedList::from(vec![1, 2]).contains(&3)).is_false();
+        assert_that(&LinkedList::new().contains(&1)).is_false();
+    }
+
+    #[test]
+    fn finds_position_of_first_match() {
+        let under_test = LinkedList::from(vec![1, 2, 3, 4]);
+
+        assert_that(&under_test.position(|v| *v > 2)).contains(2);
+        assert_that(&under_test.position(|v| *v > 4)).is_none();
+    }
+
+    #[test]
+    fn finds_first_matching_element() {
+        let under_test = LinkedList::from(vec!["a", "bb", "cc"]);
+
+        assert_that(&under_test.find(|v| v.len() == 2)).contains(&"bb");
+        assert_that(&under_test.find(|v| v.is_empty())).is_none();
+    }
+
+    #[test]
+    fn reports_empty_list_as_empty() {
+        let under_test: LinkedList<i32> = LinkedList::new();
+
+        assert_that(&under_test.is_empty()).is_true();
+    }
+
+    #[test]
+    fn reports_non_empty_list_as_not_empty() {
+        let mut under_test = LinkedList::from(vec![1]);
+
+        assert_that(&under_test.is_empty()).is_false();
+
+        under_test.pop();
+
+        assert_that(&under_test.is_empty()).is_true();
+    }
+
+    #[test]
+    fn clears_list() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        under_test.clear();
+
+        assert_that(&under_test.is_empty()).is_true();
+        assert_that(&under_test).is_equal_to(LinkedList::new());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn clears_long_list_without_overflowing_stack() {
+        let mut under_test = LinkedList::new();
+        (0..1_000_000).for_each(|v| under_test.push_front(v));
+
+        under_test.clear();
+
+        assert_that(&under_test.is_empty()).is_true();
+    }
+
+    #[test]
+    fn tracks_length_through_mutations() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.len()).is_equal_to(3);
+
+        under_test.push(4);
+        under_test.push_front(0);
+        under_test.insert(2, 5);
+
+        assert_that(&under_test.len()).is_equal_to(6);
+
+        under_test.pop();
+        under_test.pop_front();
+
+        assert_that(&under_test.len()).is_equal_to(4);
+        assert_that(&under_test.size()).is_equal_to(4);
+
+        under_test.clear();
+
+        assert_that(&under_test.len()).is_equal_to(0);
+    }
+
+    #[test]
+    fn keeps_length_when_popping_empty_list() {
+        let mut under_test: LinkedList<i32> = LinkedList::new();
+
+        under_test.pop();
+        under_test.pop_front();
+
+        assert_that(&under_test.len()).is_equal_to(0);
+    }
+
+    #[test]
+    fn reverses_list_in_place() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3, 4]);
+
+        under_test.reverse();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![4, 3, 2, 1]));
+    }
+
+    #[test]
+    fn reverses_empty_and_singleton_lists() {
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        let mut singleton = LinkedList::from(vec![1]);
+
+        empty.reverse();
+        singleton.reverse();
+
+        assert_that(&empty).is_equal_to(LinkedList::new());
+        assert_that(&singleton).is_equal_to(LinkedList::from(vec![1]));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn reverses_long_list_without_overflowing_stack() {
+        let mut under_test = LinkedList::new();
+        (0..1_000_000).for_each(|v| under_test.push_front(v));
+
+        under_test.reverse();
+
+        assert_that(&under_test.front()).contains(&0);
+        assert_that(&under_test.len()).is_equal_to(1_000_000);
+    }
+
+    #[test]
+    fn appends_other_list_and_leaves_it_empty() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+        let mut other = LinkedList::from(vec![3, 4]);
+
+        under_test.append(&mut other);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3, 4]));
+        assert_that(&other).is_equal_to(LinkedList::new());
+    }
+
+    #[test]
+    fn appends_to_and_from_empty_lists() {
+        let mut under_test = LinkedList::new();
+
+        under_test.append(&mut LinkedList::from(vec![1]));
+        under_test.append(&mut LinkedList::new());
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1]));
+    }
+
+    #[test]
+    fn prepends_other_list_preserving_order() {
+        let mut under_test = LinkedList::from(vec![3, 4]);
+
+        under_test.prepend(LinkedList::from(vec![1, 2]));
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn prepends_to_and_from_empty_lists() {
+        let mut under_test = LinkedList::new();
+
+        under_test.prepend(LinkedList::from(vec![1]));
+        under_test.prepend(LinkedList::new());
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1]));
+    }
+
+    #[test]
+    fn splits_off_tail_of_list() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3, 4]);
+
+        let tail = under_test.split_off(1);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1]));
+        assert_that(&tail).is_equal_to(LinkedList::from(vec![2, 3, 4]));
+    }
+
+    #[test]
+    fn splits_off_at_boundaries() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        assert_that(&under_test.split_off(2)).is_equal_to(LinkedList::new());
+        assert_that(&under_test.split_off(0)).is_equal_to(LinkedList::from(vec![1, 2]));
+        assert_that(&under_test).is_equal_to(LinkedList::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot split off at a nonexistent index")]
+    fn panics_when_splitting_off_out_of_bounds() {
+        LinkedList::from(vec![1]).split_off(2);
+    }
+
+    #[test]
+    fn splits_list_into_two_at_index() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.split_at(2))
+            .is_equal_to((LinkedList::from(vec![1, 2]), LinkedList::from(vec![3])));
+    }
+
+    #[test]
+    fn splits_list_at_ends() {
+        assert_that(&LinkedList::from(vec![1]).split_at(0))
+            .is_equal_to((LinkedList::new(), LinkedList::from(vec![1])));
+        assert_that(&LinkedList::from(vec![1]).split_at(1))
+            .is_equal_to((LinkedList::from(vec![1]), LinkedList::new()));
+    }
+
+    #[test]
+    fn splits_first_element_from_rest() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.split_first()).contains((1, LinkedList::from(vec![2, 3])));
+    }
+
+    #[test]
+    fn splits_last_element_from_rest() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.split_last()).contains((LinkedList::from(vec![1, 2]), 3));
+    }
+
+    #[test]
+    fn returns_none_when_splitting_empty_list() {
+        assert_that(&LinkedList::<i32>::new().split_first()).is_none();
+        assert_that(&LinkedList::<i32>::new().split_last()).is_none();
+    }
+
+    #[test]
+    fn retains_matching_elements_in_order() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3, 4, 5]);
+
+        under_test.retain(|v| v % 2 == 1);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 3, 5]));
+    }
+
+    #[test]
+    fn retains_nothing_when_no_element_matches() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        under_test.retain(|_| false);
+
+        assert_that(&under_test).is_equal_to(LinkedList::new());
+    }
+
+    #[test]
+    fn removes_consecutive_duplicates() {
+        let mut under_test = LinkedList::from(vec![1, 1, 2, 3, 3, 3, 1, 1]);
+
+        under_test.dedup();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3, 1]));
+    }
+
+    #[test]
+    fn removes_consecutive_duplicates_by_comparator() {
+        let mut under_test = LinkedList::from(vec!["a", "A", "b", "B", "a"]);
+
+        under_test.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec!["a", "b", "a"]));
+    }
+
+    #[test]
+    fn removes_consecutive_duplicates_by_key() {
+        let mut under_test = LinkedList::from(vec![10, 11, 20, 21, 22, 30]);
+
+        under_test.dedup_by_key(|v| *v / 10);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![10, 20, 30]));
+    }
+
+    #[test]
+    fn dedups_empty_and_singleton_lists() {
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        let mut singleton = LinkedList::from(vec![1]);
+
+        empty.dedup();
+        singleton.dedup();
+
+        assert_that(&empty).is_equal_to(LinkedList::new());
+        assert_that(&singleton).is_equal_to(LinkedList::from(vec![1]));
+    }
+
+    #[test]
+    fn removes_later_duplicates_anywhere_in_list() {
+        let mut under_test = LinkedList::from(vec![3, 1, 3, 2, 1, 3, 4]);
+
+        under_test.unique();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![3, 1, 2, 4]));
+    }
+
+    #[test]
+    fn keeps_list_without_duplicates_unchanged() {
+        let mut under_test = LinkedList::from(vec!["a", "b", "c"]);
+
+        under_test.unique();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec!["a", "b", "c"]));
+    }
+
+    #[test]
+    fn truncates_list_to_length() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3, 4]);
+
+        under_test.truncate(2);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2]));
+    }
+
+    #[test]
+    fn leaves_list_unchanged_when_truncating_beyond_length() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        under_test.truncate(5);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2]));
+    }
+
+    #[test]
+    fn truncates_list_to_empty() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        under_test.truncate(0);
+
+        assert_that(&under_test).is_equal_to(LinkedList::new());
+    }
+
+    #[test]
+    fn grows_list_with_generated_elements_in_order() {
+        let mut under_test = LinkedList::from(vec![1]);
+        let mut next = 1;
+
+        under_test.resize_with(4, || {
+            next += 1;
+            next
+        });
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn shrinks_list_when_resizing_below_length() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        under_test.resize_with(1, || 0);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1]));
+    }
+
+    #[test]
+    fn grows_list_with_cloned_value() {
+        let mut under_test = LinkedList::new();
+
+        under_test.resize(3, "a");
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec!["a", "a", "a"]));
+    }
+
+    #[test]
+    fn swaps_elements_at_two_positions() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3, 4]);
+
+        under_test.swap(3, 1);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 4, 3, 2]));
+    }
+
+    #[test]
+    fn swaps_element_with_itself() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        under_test.swap(1, 1);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+    fn panics_when_swapping_out_of_bounds() {
+        LinkedList::from(vec![1, 2]).swap(0, 2);
+    }
+
+    #[test]
+    fn replaces_element_and_returns_old_value() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.replace(1, 5)).contains(2);
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 5, 3]));
+    }
+
+    #[test]
+    fn returns_none_when_replacing_out_of_bounds() {
+        let mut under_test = LinkedList::from(vec![1]);
+
+        assert_that(&under_test.replace(1, 5)).is_none();
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1]));
+    }
+
+    #[test]
+    fn rotates_list_left() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3, 4, 5]);
+
+        under_test.rotate_left(2);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![3, 4, 5, 1, 2]));
+    }
+
+    #[test]
+    fn rotates_list_right() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3, 4, 5]);
+
+        under_test.rotate_right(2);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![4, 5, 1, 2, 3]));
+    }
+
+    #[test]
+    fn rotates_by_zero_and_full_length_without_change() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        under_test.rotate_left(0);
+        under_test.rotate_left(3);
+        under_test.rotate_right(3);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot rotate by more than the list length")]
+    fn panics_when_rotating_beyond_length() {
+        LinkedList::from(vec![1]).rotate_right(2);
+    }
+
+    #[test]
+    fn removes_first_equal_element() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3, 2]);
+
+        assert_that(&under_test.remove_first(&2)).contains(2);
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 3, 2]));
+    }
+
+    #[test]
+    fn returns_none_when_removing_missing_element() {
+        let mut under_test = LinkedList::from(vec![1]);
+
+        assert_that(&under_test.remove_first(&2)).is_none();
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1]));
+    }
+
+    #[test]
+    fn removes_all_equal_elements_and_counts_them() {
+        let mut under_test = LinkedList::from(vec![2, 1, 2, 3, 2]);
+
+        assert_that(&under_test.remove_all(&2)).is_equal_to(3);
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 3]));
+    }
+
+    #[test]
+    fn inserts_into_sorted_position() {
+        let mut under_test = LinkedList::from(vec![1, 3, 5]);
+
+        under_test.insert_sorted(4);
+        under_test.insert_sorted(0);
+        under_test.insert_sorted(6);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![0, 1, 3, 4, 5, 6]));
+    }
+
+    #[test]
+    fn inserts_after_equal_elements() {
+        let mut under_test = LinkedList::from(vec![(1, "a"), (2, "a")]);
+
+        under_test.insert_sorted_by_key((1, "b"), |(k, _)| *k);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![(1, "a"), (1, "b"), (2, "a")]));
+    }
+
+    #[test]
+    fn inserts_into_position_sorted_by_comparator() {
+        let mut under_test = LinkedList::from(vec![5, 3, 1]);
+
+        under_test.insert_sorted_by(2, |a, b| b.cmp(a));
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![5, 3, 2, 1]));
+    }
+
+    #[test]
+    fn sorts_list() {
+        let mut under_test = LinkedList::from(vec![5, 1, 4, 2, 3, 2]);
+
+        under_test.sort();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn sorts_empty_and_singleton_lists() {
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        let mut singleton = LinkedList::from(vec![1]);
+
+        empty.sort();
+        singleton.sort();
+
+        assert_that(&empty).is_equal_to(LinkedList::new());
+        assert_that(&singleton).is_equal_to(LinkedList::from(vec![1]));
+    }
+
+    #[test]
+    fn sorts_already_sorted_and_reversed_lists() {
+        let mut sorted = LinkedList::from(0..50);
+        let mut reversed = LinkedList::from((0..50).rev());
+
+        sorted.sort();
+        reversed.sort();
+
+        assert_that(&sorted).is_equal_to(LinkedList::from(0..50));
+        assert_that(&reversed).is_equal_to(LinkedList::from(0..50));
+    }
+
+    #[test]
+    fn sorts_list_by_comparator() {
+        let mut under_test = LinkedList::from(vec![2.5, -1.0, 3.0, 0.5]);
+
+        under_test.sort_by(f64::total_cmp);
+
+        assert_that(&under_test.to_vec()).is_equal_to(vec![-1.0, 0.5, 2.5, 3.0]);
+    }
+
+    #[test]
+    fn sorts_list_by_key() {
+        let mut under_test = LinkedList::from(vec!["ccc", "a", "bb"]);
+
+        under_test.sort_by_key(|s| s.len());
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec!["a", "bb", "ccc"]));
+    }
+
+    #[test]
+    fn keeps_equal_elements_in_original_order_when_sorting() {
+        let mut under_test =
+            LinkedList::from(vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (2, 'e')]);
+
+        under_test.sort_by_key(|(k, _)| *k);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![
+            (1, 'b'),
+            (1, 'd'),
+            (2, 'a'),
+            (2, 'c'),
+            (2, 'e'),
+        ]));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn sorts_million_element_list_without_overflowing_stack() {
+        let mut under_test = LinkedList::new();
+        let mut seed: u64 = 42;
+        for _ in 0..1_000_000 {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            under_test.push_front(seed >> 33);
+        }
+
+        under_test.sort();
+
+        assert_that(&under_test.is_sorted()).is_true();
+        assert_that(&under_test.len()).is_equal_to(1_000_000);
+    }
+
+    #[test]
+    fn keeps_sort_stable_across_merged_runs() {
+        let mut under_test = LinkedList::from((0..1000).map(|i| (i * 7 % 13, i)));
+
+        under_test.sort_by_key(|(k, _)| *k);
+
+        let mut expected: Vec<_> = (0..1000).map(|i| (i * 7 % 13, i)).collect();
+        expected.sort_by_key(|(k, _)| *k);
+        assert_that(&under_test.to_vec()).is_equal_to(expected);
+    }
+
+    #[test]
+    fn reports_whether_list_is_sorted() {
+        assert_that(&LinkedList::from(vec![1, 2, 2, 3]).is_sorted()).is_true();
+        assert_that(&LinkedList::from(vec![1, 3, 2]).is_sorted()).is_false();
+        assert_that(&LinkedList::<i32>::new().is_sorted()).is_true();
+        assert_that(&LinkedList::from(vec![1]).is_sorted()).is_true();
+    }
+
+    #[test]
+    fn reports_whether_list_is_sorted_by_comparator() {
+        let under_test = LinkedList::from(vec![3, 2, 1]);
+
+        assert_that(&under_test.is_sorted_by(|a, b| a >= b)).is_true();
+        assert_that(&under_test.is_sorted_by(|a, b| a <= b)).is_false();
+    }
+
+    #[test]
+    fn reports_whether_list_is_sorted_by_key() {
+        let under_test = LinkedList::from(vec!["a", "bb", "cc", "ddd"]);
+
+        assert_that(&under_test.is_sorted_by_key(|s| s.len())).is_true();
+        assert_that(&under_test.is_sorted_by_key(|s| std::cmp::Reverse(s.len()))).is_false();
+    }
+
+    #[test]
+    fn is_sorted_after_sorting() {
+        let mut under_test = LinkedList::from(vec![3, 1, 2]);
+
+        under_test.sort();
+
+        assert_that(&under_test.is_sorted()).is_true();
+    }
+
+    #[test]
+    fn spans_longest_matching_prefix() {
+        let under_test = LinkedList::from(vec![1, 2, 5, 1, 6]);
+
+        assert_that(&under_test.span(|v| *v < 3)).is_equal_to((
+            LinkedList::from(vec![1, 2]),
+            LinkedList::from(vec![5, 1, 6]),
+        ));
+    }
+
+    #[test]
+    fn spans_whole_list_or_nothing() {
+        assert_that(&LinkedList::from(vec![1, 2]).span(|_| true))
+            .is_equal_to((LinkedList::from(vec![1, 2]), LinkedList::new()));
+        assert_that(&LinkedList::from(vec![1, 2]).span(|_| false))
+            .is_equal_to((LinkedList::new(), LinkedList::from(vec![1, 2])));
+    }
+
+    #[test]
+    fn finds_index_of_value_in_sorted_list() {
+        let under_test = LinkedList::from(vec![1, 3, 5, 7]);
+
+        assert_that(&under_test.search_sorted(&5)).is_equal_to(Ok(2));
+    }
+
+    #[test]
+    fn returns_insertion_point_for_missing_value_in_sorted_list() {
+        let under_test = LinkedList::from(vec![1, 3, 5, 7]);
+
+        assert_that(&under_test.search_sorted(&0)).is_equal_to(Err(0));
+        assert_that(&under_test.search_sorted(&4)).is_equal_to(Err(2));
+        assert_that(&under_test.search_sorted(&8)).is_equal_to(Err(4));
+    }
+
+    #[test]
+    fn searches_sorted_list_by_key() {
+        let under_test = LinkedList::from(vec![(1, 'a'), (2, 'b'), (4, 'c')]);
+
+        assert_that(&under_test.search_sorted_by_key(&2, |(k, _)| *k)).is_equal_to(Ok(1));
+        assert_that(&under_test.search_sorted_by_key(&3, |(k, _)| *k)).is_equal_to(Err(2));
+    }
+
+    #[test]
+    fn checks_prefix_against_slice_list_and_iterator() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.starts_with(&[1, 2])).is_true();
+        assert_that(&under_test.starts_with(&LinkedList::from(vec![1, 2, 3]))).is_true();
+        assert_that(&under_test.starts_with([1].iter())).is_true();
+        assert_that(&under_test.starts_with(&[])).is_true();
+        assert_that(&under_test.starts_with(&[2])).is_false();
+        assert_that(&under_test.starts_with(&[1, 2, 3, 4])).is_false();
+    }
+
+    #[test]
+    fn checks_suffix_against_slice_list_and_iterator() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.ends_with(&[2, 3])).is_true();
+        assert_that(&under_test.ends_with(&LinkedList::from(vec![1, 2, 3]))).is_true();
+        assert_that(&under_test.ends_with([3].iter())).is_true();
+        assert_that(&under_test.ends_with(&[])).is_true();
+        assert_that(&under_test.ends_with(&[2])).is_false();
+        assert_that(&under_test.ends_with(&[0, 1, 2, 3])).is_false();
+    }
+
+    #[test]
+    fn strips_matching_prefix() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.strip_prefix(&[1, 2])).is_true();
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![3]));
+    }
+
+    #[test]
+    fn keeps_list_when_prefix_does_not_match() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.strip_prefix(&[2])).is_false();
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn strips_matching_suffix() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.strip_suffix(&LinkedList::from(vec![2, 3]))).is_true();
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1]));
+    }
+
+    #[test]
+    fn keeps_list_when_suffix_does_not_match() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.strip_suffix(&[1, 2, 3, 4])).is_false();
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn finds_index_of_contiguous_subsequence() {
+        let under_test = LinkedList::from(vec![1, 2, 1, 2, 3, 4]);
+
+        assert_that(&under_test.find_subsequence(&[1, 2, 3])).contains(2);
+        assert_that(&under_test.find_subsequence(&LinkedList::from(vec![3, 4]))).contains(4);
+    }
+
+    #[test]
+    fn returns_none_when_subsequence_is_missing() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.find_subsequence(&[1, 3])).is_none();
+        assert_that(&under_test.find_subsequence(&[1, 2, 3, 4])).is_none();
+    }
+
+    #[test]
+    fn finds_empty_subsequence_at_start() {
+        assert_that(&LinkedList::<i32>::new().find_subsequence(&[])).contains(0);
+        assert_that(&LinkedList::from(vec![1]).find_subsequence(&[])).contains(0);
+    }
+
+    #[test]
+    fn moves_element_to_front() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3, 4]);
+
+        under_test.move_to_front(2);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![3, 1, 2, 4]));
+    }
+
+    #[test]
+    fn moves_last_and_first_elements_to_front() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        under_test.move_to_front(2);
+        under_test.move_to_front(0);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![3, 1, 2]));
+        assert_that(&under_test.back()).contains(&2);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 1 but the index is 1")]
+    fn panics_when_moving_out_of_bounds_to_front() {
+        LinkedList::from(vec![1]).move_to_front(1);
+    }
+
+    #[test]
+    fn pops_front_only_when_predicate_matches() {
+        let mut under_test = LinkedList::from(vec![1, 5, 2]);
+
+        assert_that(&under_test.pop_front_if(|v| *v < 3)).contains(1);
+        assert_that(&under_test.pop_front_if(|v| *v < 3)).is_none();
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![5, 2]));
+    }
+
+    #[test]
+    fn pops_back_only_when_predicate_matches() {
+        let mut under_test = LinkedList::from(vec![5, 1, 2]);
+
+        assert_that(&under_test.pop_if(|v| *v == 2)).contains(2);
+        assert_that(&under_test.pop_if(|v| *v == 2)).is_none();
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![5, 1]));
+    }
+
+    #[test]
+    fn returns_none_when_conditionally_popping_empty_list() {
+        let mut under_test: LinkedList<i32> = LinkedList::new();
+
+        assert_that(&under_test.pop_if(|_| true)).is_none();
+        assert_that(&under_test.pop_front_if(|_| true)).is_none();
+    }
+
+    #[test]
+    fn maps_elements_into_new_list_in_order() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.map(|v| v.to_string())).is_equal_to(LinkedList::from(vec![
+            "1".to_string(),
+            "2".to_string(),
+            "3".to_string(),
+        ]));
+    }
+
+    #[test]
+    fn applies_map_function_front_to_back() {
+        let mut seen = vec![];
+
+        let mapped = LinkedList::from(vec![1, 2, 3]).map(|v| {
+            seen.push(v);
+            v * 2
+        });
+
+        assert_that(&seen).is_equal_to(vec![1, 2, 3]);
+        assert_that(&mapped).is_equal_to(LinkedList::from(vec![2, 4, 6]));
+    }
+
+    #[test]
+    fn maps_empty_list() {
+        assert_that(&LinkedList::<i32>::new().map(|v| v + 1)).is_equal_to(LinkedList::new());
+    }
+
+    #[test]
+    fn filters_elements_into_list_in_order() {
+        let under_test = LinkedList::from(vec![1, 2, 3, 4]);
+
+        assert_that(&under_test.filter(|v| v % 2 == 0)).is_equal_to(LinkedList::from(vec![2, 4]));
+    }
+
+    #[test]
+    fn filter_maps_elements_into_new_list_in_order() {
+        let under_test = LinkedList::from(vec!["1", "x", "3"]);
+
+        assert_that(&under_test.filter_map(|s| s.parse::<i32>().ok()))
+            .is_equal_to(LinkedList::from(vec![1, 3]));
+    }
+
+    #[test]
+    fn flattens_nested_lists_in_order() {
+        let under_test = LinkedList::from(vec![
+            LinkedList::from(vec![1, 2]),
+            LinkedList::new(),
+            LinkedList::from(vec![3]),
+            LinkedList::from(vec![4, 5]),
+        ]);
+
+        assert_that(&under_test.flatten()).is_equal_to(LinkedList::from(vec![1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn flattens_empty_outer_list() {
+        let under_test: LinkedList<LinkedList<i32>> = LinkedList::new();
+
+        assert_that(&under_test.flatten()).is_equal_to(LinkedList::new());
+    }
+
+    #[test]
+    fn flat_maps_elements_into_concatenated_list() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.flat_map(|v| LinkedList::from(vec![v; v])))
+            .is_equal_to(LinkedList::from(vec![1, 2, 2, 3, 3, 3]));
+    }
+
+    #[test]
+    fn zips_lists_into_pairs() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.zip(LinkedList::from(vec!['a', 'b', 'c'])))
+            .is_equal_to(LinkedList::from(vec![(1, 'a'), (2, 'b'), (3, 'c')]));
+    }
+
+    #[test]
+    fn stops_zipping_at_shorter_list() {
+        assert_that(&LinkedList::from(vec![1, 2, 3]).zip(LinkedList::from(vec!['a'])))
+            .is_equal_to(LinkedList::from(vec![(1, 'a')]));
+        assert_that(&LinkedList::from(vec![1]).zip(LinkedList::from(vec!['a', 'b'])))
+            .is_equal_to(LinkedList::from(vec![(1, 'a')]));
+    }
+
+    #[test]
+    fn combines_lists_element_wise() {
+        let values = LinkedList::from(vec![1.0, 2.0, 3.0]);
+        let weights = LinkedList::from(vec![0.5, 0.25, 2.0]);
+
+        assert_that(&values.zip_with(weights, |v, w| v * w).to_vec())
+            .is_equal_to(vec![0.5, 0.5, 6.0]);
+    }
+
+    #[test]
+    fn stops_combining_at_shorter_list() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.zip_with(LinkedList::from(vec![10]), |a, b| a + b))
+            .is_equal_to(LinkedList::from(vec![11]));
+    }
+
+    #[test]
+    fn unzips_pairs_into_two_lists() {
+        let under_test = LinkedList::from(vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+
+        assert_that(&under_test.unzip()).is_equal_to((
+            LinkedList::from(vec![1, 2, 3]),
+            LinkedList::from(vec!['a', 'b', 'c']),
+        ));
+    }
+
+    #[test]
+    fn unzips_empty_list() {
+        let under_test: LinkedList<(i32, char)> = LinkedList::new();
+
+        assert_that(&under_test.unzip()).is_equal_to((LinkedList::new(), LinkedList::new()));
+    }
+
+    #[test]
+    fn interleaves_lists_alternately() {
+        let under_test = LinkedList::from(vec![1, 3, 5]);
+
+        assert_that(&under_test.interleave(LinkedList::from(vec![2, 4, 6])))
+            .is_equal_to(LinkedList::from(vec![1, 2, 3, 4, 5, 6]));
+    }
+
+    #[test]
+    fn appends_remainder_of_longer_list_when_interleaving() {
+        assert_that(&LinkedList::from(vec![1, 3]).interleave(LinkedList::from(vec![2, 4, 5, 6])))
+            .is_equal_to(LinkedList::from(vec![1, 2, 3, 4, 5, 6]));
+        assert_that(&LinkedList::from(vec![1, 3, 4]).interleave(LinkedList::from(vec![2])))
+            .is_equal_to(LinkedList::from(vec![1, 2, 3, 4]));
+        assert_that(&LinkedList::new().interleave(LinkedList::from(vec![1])))
+            .is_equal_to(LinkedList::from(vec![1]));
+    }
+
+    #[test]
+    fn builds_list_from_index_function() {
+        assert_that(&LinkedList::from_fn(4, |i| i * i))
+            .is_equal_to(LinkedList::from(vec![0, 1, 4, 9]));
+        assert_that(&LinkedList::from_fn(0, |i| i)).is_equal_to(LinkedList::new());
+    }
+
+    #[test]
+    fn builds_list_from_successors() {
+        let under_test =
+            LinkedList::successors(Some(1), |v| if *v < 100 { Some(v * 3) } else { None });
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 3, 9, 27, 81, 243]));
+        assert_that(&LinkedList::successors(None, |v: &i32| Some(*v)))
+            .is_equal_to(LinkedList::new());
+    }
+
+    #[test]
+    fn builds_list_by_unfolding_state() {
+        let under_test = LinkedList::unfold((0, 1), |(a, b)| {
+            if *a > 20 {
+                return None;
+            }
+            let value = *a;
+            (*a, *b) = (*b, *a + *b);
+            Some(value)
+        });
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![0, 1, 1, 2, 3, 5, 8, 13]));
+    }
+
+    #[test]
+    fn builds_list_of_repeated_values() {
+        assert_that(&LinkedList::repeat("a", 3)).is_equal_to(LinkedList::from(vec!["a", "a", "a"]));
+        assert_that(&LinkedList::repeat(1, 0)).is_equal_to(LinkedList::new());
+    }
+
+    #[test]
+    fn builds_list_of_generated_values() {
+        let mut next = 0;
+
+        let under_test = LinkedList::repeat_with(
+            || {
+                next += 2;
+                next
+            },
+            3,
+        );
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![2, 4, 6]));
+    }
+
+    #[test]
+    fn scans_running_totals_into_new_list() {
+        let under_test = LinkedList::from(vec![1, 2, 3, 4]);
+
+        assert_that(&under_test.scan(0, |sum, v| {
+            *sum += v;
+            Some(*sum)
+        }))
+        .is_equal_to(LinkedList::from(vec![1, 3, 6, 10]));
+    }
+
+    #[test]
+    fn stops_scanning_when_function_returns_none() {
+        let under_test = LinkedList::from(vec![1, 2, 3, 4]);
+
+        assert_that(&under_test.scan(1, |product, v| {
+            *product *= v;
+            (*product < 10).then_some(*product)
+        }))
+        .is_equal_to(LinkedList::from(vec![1, 2, 6]));
+    }
+
+    #[test]
+    fn transposes_rows_into_columns() {
+        let under_test = LinkedList::from(vec![
+            LinkedList::from(vec![1, 2, 3]),
+            LinkedList::from(vec![4, 5, 6]),
+        ]);
+
+        assert_that(&under_test.transpose()).is_equal_to(LinkedList::from(vec![
+            LinkedList::from(vec![1, 4]),
+            LinkedList::from(vec![2, 5]),
+            LinkedList::from(vec![3, 6]),
+        ]));
+    }
+
+    #[test]
+    fn stops_transposing_at_shortest_row() {
+        let under_test = LinkedList::from(vec![
+            LinkedList::from(vec![1, 2, 3]),
+            LinkedList::from(vec![4]),
+        ]);
+
+        assert_that(&under_test.transpose())
+            .is_equal_to(LinkedList::from(vec![LinkedList::from(vec![1, 4])]));
+        assert_that(&LinkedList::<LinkedList<i32>>::new().transpose())
+            .is_equal_to(LinkedList::new());
+    }
+
+    #[test]
+    fn pads_short_rows_when_transposing() {
+        let under_test = LinkedList::from(vec![
+            LinkedList::from(vec![1, 2]),
+            LinkedList::from(vec![3]),
+        ]);
+
+        assert_that(&under_test.transpose_padded()).is_equal_to(LinkedList::from(vec![
+            LinkedList::from(vec![Some(1), Some(3)]),
+            LinkedList::from(vec![Some(2), None]),
+        ]));
+    }
+
+    #[test]
+    fn groups_elements_by_key_preserving_order() {
+        let under_test = LinkedList::from(vec![1, 2, 3, 4, 5, 6, 7]);
+
+        let groups = under_test.group_by_key(|v| v % 3);
+
+        assert_that(&groups.len()).is_equal_to(3);
+        assert_that(&groups[&0]).is_equal_to(LinkedList::from(vec![3, 6]));
+        assert_that(&groups[&1]).is_equal_to(LinkedList::from(vec![1, 4, 7]));
+        assert_that(&groups[&2]).is_equal_to(LinkedList::from(vec![2, 5]));
+    }
+
+    #[test]
+    fn moves_nodes_into_groups() {
+        let under_test = LinkedList::from(vec![1, 2, 3, 4, 5]);
+        let addresses: Vec<_> = under_test.iter().map(|v| v as *const i32).collect();
+
+        let mut groups = under_test.group_by_key(|v| v % 2);
+
+        let odd: Vec<_> = groups[&1].iter().map(|v| v as *const i32).collect();
+        let even: Vec<_> = groups[&0].iter().map(|v| v as *const i32).collect();
+        assert_that(&odd).is_equal_to(vec![addresses[0], addresses[2], addresses[4]]);
+        assert_that(&even).is_equal_to(vec![addresses[1], addresses[3]]);
+        for group in groups.values_mut() {
+            assert_back_matches_last(group);
+        }
+    }
+
+    #[test]
+    fn groups_empty_list_into_empty_map() {
+        let under_test: LinkedList<i32> = LinkedList::new();
+
+        assert_that(&under_test.group_by_key(|v| *v).is_empty()).is_true();
+    }
+
+    #[test]
+    fn counts_element_frequencies() {
+        let under_test = LinkedList::from(vec!["a", "b", "a", "c", "a"]);
+
+        let counts = under_test.counts();
+
+        assert_that(&counts).is_equal_to(HashMap::from([("a", 3), ("b", 1), ("c", 1)]));
+        assert_that(&under_test.len()).is_equal_to(5);
+    }
+
+    #[test]
+    fn counts_element_frequencies_by_consuming_list() {
+        let under_test = LinkedList::from(vec![String::from("x"), String::from("x")]);
+
+        assert_that(&under_test.into_counts()).is_equal_to(HashMap::from([(String::from("x"), 2)]));
+    }
+
+    #[test]
+    fn clones_list() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.clone()).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn clones_into_longer_list() {
+        let mut under_test = LinkedList::from(vec![9, 9, 9, 9]);
+
+        under_test.clone_from(&LinkedList::from(vec![1, 2]));
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2]));
+    }
+
+    #[test]
+    fn clones_into_shorter_list() {
+        let mut under_test = LinkedList::from(vec![9]);
+
+        under_test.clone_from(&LinkedList::from(vec![1, 2, 3]));
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn reuses_existing_element_buffers_when_cloning_into_list() {
+        let mut under_test = LinkedList::from(vec![String::with_capacity(64)]);
+        let buffer = under_test.front().unwrap().as_ptr();
+
+        under_test.clone_from(&LinkedList::from(vec![String::from("a")]));
+
+        assert_that(&under_test.front().unwrap().as_ptr()).is_equal_to(buffer);
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![String::from("a")]));
+    }
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn hashes_equal_lists_equally() {
+        assert_that(&hash_of(&LinkedList::from(vec![1, 2, 3])))
+            .is_equal_to(hash_of(&LinkedList::from(vec![1, 2, 3])));
+    }
+
+    #[test]
+    fn hashes_element_order_and_nesting_distinctly() {
+        assert_that(&hash_of(&LinkedList::from(vec![1, 2])))
+            .is_not_equal_to(hash_of(&LinkedList::from(vec![2, 1])));
+        assert_that(&hash_of(&LinkedList::from(vec![
+            LinkedList::from(vec![1]),
+            LinkedList::from(vec![2]),
+        ])))
+        .is_not_equal_to(hash_of(&LinkedList::from(vec![
+            LinkedList::from(vec![1, 2]),
+            LinkedList::new(),
+        ])));
+    }
+
+    #[test]
+    fn uses_lists_as_set_keys() {
+        let set = HashSet::from([
+            LinkedList::from(vec![1, 2]),
+            LinkedList::from(vec![1, 2]),
+            LinkedList::from(vec![2]),
+        ]);
+
+        assert_that(&set.len()).is_equal_to(2);
+        assert_that(&set.contains(&LinkedList::from(vec![2]))).is_true();
+    }
+
+    #[test]
+    fn orders_lists_lexicographically() {
+        assert_that(&(LinkedList::from(vec![1, 2]) < LinkedList::from(vec![1, 3]))).is_true();
+        assert_that(&(LinkedList::from(vec![2]) > LinkedList::from(vec![1, 9]))).is_true();
+        assert_that(&LinkedList::from(vec![1, 2]).cmp(&LinkedList::from(vec![1, 2])))
+            .is_equal_to(Ordering::Equal);
+    }
+
+    #[test]
+    fn orders_prefix_before_longer_list() {
+        assert_that(&(LinkedList::new() < LinkedList::from(vec![0]))).is_true();
+        assert_that(&(LinkedList::from(vec![1]) < LinkedList::from(vec![1, 0]))).is_true();
+    }
+
+    #[test]
+    fn compares_lists_of_partially_ordered_elements() {
+        assert_that(&LinkedList::from(vec![1.0]).partial_cmp(&LinkedList::from(vec![f64::NAN])))
+            .is_none();
+        assert_that(&(LinkedList::from(vec![1.0, 2.0]) < LinkedList::from(vec![1.5]))).is_true();
+    }
+
+    #[test]
+    fn orders_lists_consistently_with_vec() {
+        let lists = vec![vec![3], vec![1, 2, 3], vec![], vec![1, 2], vec![2, 0]];
+
+        let mut expected = lists.clone();
+        expected.sort();
+        let mut under_test: Vec<_> = lists.into_iter().map(LinkedList::from).collect();
+        under_test.sort();
+
+        assert_that(&under_test).is_equal_to(
+            expected
+                .into_iter()
+                .map(LinkedList::from)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn displays_elements_joined_by_arrows() {
+        assert_that(&LinkedList::from(vec![1, 2, 3]).to_string())
+            .is_equal_to("[1 -> 2 -> 3]".to_string());
+        assert_that(&LinkedList::from(vec!["a"]).to_string()).is_equal_to("[a]".to_string());
+        assert_that(&LinkedList::<i32>::new().to_string()).is_equal_to("[]".to_string());
+    }
+
+    #[test]
+    fn applies_format_options_to_each_element() {
+        let under_test = LinkedList::from(vec![1.0, 2.25]);
+
+        assert_that(&format!("{under_test:.1}")).is_equal_to("[1.0 -> 2.2]".to_string());
+    }
+
+    #[test]
+    fn joins_displayed_elements_with_separator() {
+        assert_that(&LinkedList::from(vec![1, 2, 3]).join(", ")).is_equal_to("1, 2, 3".to_string());
+        assert_that(&LinkedList::from(vec![1]).join(", ")).is_equal_to("1".to_string());
+        assert_that(&LinkedList::<i32>::new().join(", ")).is_equal_to(String::new());
+    }
+
+    #[test]
+    fn joins_string_elements_with_separator() {
+        let owned = LinkedList::from(vec![String::from("a"), String::from("b")]);
+        let borrowed = LinkedList::from(vec!["x", "", "z"]);
+
+        assert_that(&owned.join_str("-")).is_equal_to("a-b".to_string());
+        assert_that(&borrowed.join_str("/")).is_equal_to("x//z".to_string());
+        assert_that(&LinkedList::<&str>::new().join_str("/")).is_equal_to(String::new());
+    }
+
+    #[test]
+    fn debug_formats_elements_as_flat_list() {
+        assert_that(&format!("{:?}", LinkedList::from(vec![1, 2, 3])))
+            .is_equal_to("LinkedList [1, 2, 3]".to_string());
+        assert_that(&format!("{:?}", LinkedList::<i32>::new()))
+            .is_equal_to("LinkedList []".to_string());
+    }
+
+    #[test]
+    fn debug_formats_nested_elements() {
+        let under_test = LinkedList::from(vec![Some("a"), None]);
+
+        assert_that(&format!("{under_test:?}"))
+            .is_equal_to(r#"LinkedList [Some("a"), None]"#.to_string());
+        assert_that(&format!("{under_test:#?}")).is_equal_to(
+            "LinkedList [\n    Some(\n        \"a\",\n    ),\n    None,\n]".to_string(),
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn debug_formats_long_list_without_overflowing_stack() {
+        let mut under_test = LinkedList::new();
+        (0..200_000).for_each(|v| under_test.push_front(v % 10));
+
+        assert_that(&format!("{under_test:?}").len())
+            .is_equal_to("LinkedList [".len() + 200_000 * 3 - 2 + 1);
+    }
+
+    #[test]
+    fn extends_list_at_the_back() {
+        let mut under_test = LinkedList::from(vec![1, 2]);
+
+        under_test.extend(vec![3, 4]);
+        under_test.extend(&[5]);
+        under_test.extend(std::iter::empty::<i32>());
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3, 4, 5]));
+        assert_that(&under_test.len()).is_equal_to(5);
+    }
+
+    #[test]
+    fn extends_empty_list() {
+        let mut under_test = LinkedList::new();
+
+        under_test.extend(0..3);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn extends_list_at_the_front_preserving_order() {
+        let mut under_test = LinkedList::from(vec![4, 5]);
+
+        under_test.extend_front(vec![1, 2, 3]);
+        under_test.extend_front(std::iter::empty());
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3, 4, 5]));
+        assert_that(&under_test.len()).is_equal_to(5);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn extends_large_list_repeatedly() {
+        let mut under_test = LinkedList::new();
+
+        (0..1_000).for_each(|i| under_test.extend(i * 100..(i + 1) * 100));
+
+        assert_that(&under_test.len()).is_equal_to(100_000);
+        assert_that(&under_test.back()).contains_value(&99_999);
+    }
+
+    #[test]
+    fn converts_from_vec() {
+        let under_test: LinkedList<i32> = vec![1, 2, 3].into();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+        assert_that(&under_test.len()).is_equal_to(3);
+    }
+
+    #[test]
+    fn converts_from_array() {
+        let under_test: LinkedList<_> = [1, 2, 3].into();
+        let empty: LinkedList<i32> = [].into();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+        assert_that(&empty).is_equal_to(LinkedList::new());
+    }
+
+    #[test]
+    fn converts_from_slice_by_cloning() {
+        let source = vec!["a".to_string(), "b".to_string()];
+
+        let under_test: LinkedList<String> = source.as_slice().into();
+
+        assert_that(&under_test.to_vec()).is_equal_to(source);
+    }
+
+    #[test]
+    fn resolves_path_call_to_inherent_from_and_into_to_from_impl() {
+        let source = [1, 2];
+
+        let borrowed: LinkedList<&i32> = LinkedList::from(&source[..]);
+        let cloned: LinkedList<i32> = (&source[..]).into();
+        let via_trait = <LinkedList<i32> as From<&[i32]>>::from(&source[..]);
+
+        assert_that(&std::ptr::eq(*borrowed.front().unwrap(), &source[0])).is_true();
+        assert_that(&cloned).is_equal_to(LinkedList::from(vec![1, 2]));
+        assert_that(&via_trait).is_equal_to(cloned);
+    }
+
+    #[test]
+    fn converts_from_vec_via_generic_bound() {
+        fn convert<L: From<Vec<u8>>>(vec: Vec<u8>) -> L {
+            L::from(vec)
+        }
+
+        assert_that(&convert::<LinkedList<u8>>(vec![7, 8]))
+            .is_equal_to(LinkedList::from(vec![7, 8]));
+    }
+
+    #[test]
+    fn converts_into_vec() {
+        let under_test: Vec<i32> = LinkedList::from(vec![1, 2, 3]).into();
+
+        assert_that(&under_test).is_equal_to(vec![1, 2, 3]);
+        assert_that(&under_test.capacity()).is_equal_to(3);
+    }
+
+    #[test]
+    fn converts_into_vec_deque() {
+        let under_test: VecDeque<i32> = LinkedList::from(vec![1, 2, 3]).into();
+
+        assert_that(&under_test).is_equal_to(VecDeque::from(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn converts_into_boxed_slice() {
+        let under_test: Box<[&str]> = LinkedList::from(vec!["a", "b"]).into();
+        let empty: Box<[i32]> = LinkedList::new().into();
+
+        assert_that(&under_test.as_ref()).is_equal_to(["a", "b"].as_slice());
+        assert_that(&empty.is_empty()).is_true();
+    }
+
+    #[test]
+    fn converts_from_std_linked_list() {
+        let source: std::collections::LinkedList<i32> = (1..=3).collect();
+
+        let under_test: LinkedList<_> = source.into();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+        assert_that(&under_test.len()).is_equal_to(3);
+    }
+
+    #[test]
+    fn converts_into_std_linked_list() {
+        let under_test: std::collections::LinkedList<i32> = LinkedList::from(vec![1, 2, 3]).into();
+
+        assert_that(&under_test.into_iter().collect::<Vec<_>>()).is_equal_to(vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn round_trips_through_std_linked_list() {
+        let original = LinkedList::from(vec!["a", "b", "c"]);
+
+        let std_list: std::collections::LinkedList<&str> = original.clone().into();
+
+        let round_tripped: LinkedList<_> = std_list.into();
+
+        assert_that(&round_tripped).is_equal_to(original);
+    }
+
+    #[test]
+    fn equals_vec_with_same_elements() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_eq!(under_test, vec![1, 2, 3]);
+        assert_ne!(under_test, vec![1, 2]);
+        assert_ne!(under_test, vec![1, 2, 4]);
+        assert_eq!(LinkedList::<i32>::new(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn equals_slice_with_same_elements() {
+        let under_test = LinkedList::from(vec!["a".to_string(), "b".to_string()]);
+
+        assert_that(&(under_test == *["a", "b"].as_slice())).is_true();
+        assert_that(&(under_test == *["a"].as_slice())).is_false();
+    }
+
+    #[test]
+    fn compares_lists_of_floats() {
+        let under_test = LinkedList::from(vec![1.5, 2.0]);
+
+        assert_that(&(under_test == LinkedList::from(vec![1.5, 2.0]))).is_true();
+        assert_that(&(under_test == LinkedList::from(vec![1.5, f64::NAN]))).is_false();
+        assert_that(&(LinkedList::from(vec![f64::NAN]) == LinkedList::from(vec![f64::NAN])))
+            .is_false();
+    }
+
+    #[test]
+    fn compares_lists_of_different_element_types() {
+        let under_test = LinkedList::from(vec!["a".to_string(), "b".to_string()]);
+
+        assert_that(&(under_test == LinkedList::from(vec!["a", "b"]))).is_true();
+        assert_that(&(under_test == LinkedList::from(vec!["a", "c"]))).is_false();
+        assert_that(&(under_test == LinkedList::from(vec!["a"]))).is_false();
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn compares_long_lists_without_overflowing_stack() {
+        let mut a = LinkedList::new();
+        let mut b = LinkedList::new();
+        (0..1_000_000).for_each(|v| {
+            a.push_front(v);
+            b.push_front(v);
+        });
+
+        assert_that(&(a == b)).is_true();
+    }
+
+    #[test]
+    fn adds_lists_by_concatenation() {
+        let under_test =
+            LinkedList::from(vec![1, 2]) + LinkedList::from(vec![3]) + LinkedList::new();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+        assert_that(&under_test.len()).is_equal_to(3);
+    }
+
+    #[test]
+    fn adds_to_empty_list() {
+        let under_test = LinkedList::new() + LinkedList::from(vec!["a"]);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec!["a"]));
+    }
+
+    #[test]
+    fn add_assign_appends_list() {
+        let mut under_test = LinkedList::from(vec![1]);
+
+        under_test += LinkedList::from(vec![2, 3]);
+        under_test += LinkedList::new();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+        assert_that(&under_test.len()).is_equal_to(3);
+    }
+
+    #[test]
+    fn sums_lists_by_concatenation() {
+        let under_test: LinkedList<i32> = (0..4).map(|i| LinkedList::repeat(i, i as usize)).sum();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 2, 3, 3, 3]));
+        assert_that(&under_test.len()).is_equal_to(6);
+    }
+
+    #[test]
+    fn sums_no_lists_to_empty_list() {
+        let under_test: LinkedList<i32> = std::iter::empty().sum();
+
+        assert_that(&under_test.is_empty()).is_true();
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn sums_many_small_lists() {
+        let under_test: LinkedList<i32> = (0..100_000).map(|i| LinkedList::from(vec![i])).sum();
+
+        assert_that(&under_test.len()).is_equal_to(100_000);
+        assert_that(&under_test.back()).contains_value(&99_999);
+    }
+
+    #[test]
+    fn into_vec_consumes_list_in_order() {
+        let under_test = LinkedList::from(vec!["a".to_string(), "b".to_string()]);
+
+        assert_that(&under_test.into_vec()).is_equal_to(vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn to_vec_clones_without_consuming_list() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        let vec = under_test.to_vec();
+
+        assert_that(&vec).is_equal_to(vec![1, 2, 3]);
+        assert_that(&vec.capacity()).is_equal_to(3);
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn into_sorted_vec_returns_sorted_elements() {
+        let under_test = LinkedList::from(vec![3, 1, 2, 1]);
+
+        assert_that(&under_test.into_sorted_vec()).is_equal_to(vec![1, 1, 2, 3]);
+        assert_that(&LinkedList::<i32>::new().into_sorted_vec()).is_equal_to(vec![]);
+    }
+
+    #[test]
+    fn into_sorted_vec_is_stable() {
+        #[derive(Debug)]
+        struct Tagged(u8, char);
+
+        impl PartialEq for Tagged {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for Tagged {}
+
+        impl PartialOrd for Tagged {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Tagged {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let under_test = LinkedList::from(vec![
+            Tagged(2, 'a'),
+            Tagged(1, 'b'),
+            Tagged(2, 'c'),
+            Tagged(1, 'd'),
+        ]);
+
+        let tags: Vec<char> = under_test.into_sorted_vec().iter().map(|t| t.1).collect();
+
+        assert_that(&tags).is_equal_to(vec!['b', 'd', 'a', 'c']);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn pushes_onto_multi_million_element_list() {
+        let mut under_test = LinkedList::new();
+        (0..3_000_000).for_each(|v| under_test.push_front(v));
+
+        under_test.push(-1);
+        under_test.push(-2);
+
+        assert_that(&under_test.len()).is_equal_to(3_000_002);
+        assert_that(&under_test.back()).contains_value(&-2);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn pops_from_multi_million_element_list() {
+        let mut under_test = LinkedList::new();
+        (0..3_000_000).for_each(|v| under_test.push_front(v));
+
+        assert_that(&under_test.pop()).contains_value(0);
+        assert_that(&under_test.pop()).contains_value(1);
+        assert_that(&under_test.len()).is_equal_to(2_999_998);
+        assert_that(&under_test.back()).contains_value(&2);
+    }
+
+    #[test]
+    fn pops_every_element_from_the_back() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        assert_that(&under_test.pop()).contains_value(3);
+        assert_that(&under_test.pop()).contains_value(2);
+        assert_that(&under_test.pop()).contains_value(1);
+        assert_that(&under_test.pop()).is_none();
+        assert_that(&under_test.is_empty()).is_true();
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn drops_ten_million_element_list() {
+        let mut under_test = LinkedList::new();
+        (0..10_000_000).for_each(|v| under_test.push_front(v));
+
+        assert_that(&under_test.len()).is_equal_to(10_000_000);
+        drop(under_test);
+    }
+
+    #[test]
+    fn drops_every_element_exactly_once() {
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let under_test = LinkedList::repeat_with(|| Rc::clone(&counter), 1_000);
+
+        assert_that(&Rc::strong_count(&counter)).is_equal_to(1_001);
+        drop(under_test);
+        assert_that(&Rc::strong_count(&counter)).is_equal_to(1);
+    }
+
+    fn assert_back_matches_last<T: PartialEq + fmt::Debug>(list: &mut LinkedList<T>) {
+        // walk the nodes rather than trusting the cached tail
+        let last = list
+            .len()
+            .checked_sub(1)
+            .and_then(|index| list.iter().nth(index))
+            .map(|v| v as *const T);
+
+        assert_that(&list.back().map(|v| v as *const T)).is_equal_to(last);
+        assert_that(&list.back_mut().map(|v| v as *const T)).is_equal_to(last);
+    }
+
+    #[test]
+    fn back_tracks_last_element_through_mutations() {
+        let mut under_test = LinkedList::new();
+        assert_back_matches_last(&mut under_test);
+
+        let steps: Vec<fn(&mut LinkedList<i32>)> = vec![
+            |l| l.push(1),
+            |l| l.push(2),
+            |l| l.push_front(0),
+            |l| l.insert(3, 3),
+            |l| l.insert(1, 9),
+            |l| l.reverse(),
+            |l| l.sort(),
+            |l| l.move_to_front(4),
+            |l| l.move_to_front(3),
+            |l| l.retain(|v| *v != 2),
+            |l| {
+                let mut other = LinkedList::from(vec![7]);
+                l.append(&mut other);
+            },
+            |l| l.dedup(),
+            |l| l.rotate_left(2),
+            |l| l.truncate(3),
+            |l| {
+                l.pop();
+            },
+            |l| l.extend(vec![5, 5, 6]),
+            |l| l.dedup(),
+            |l| {
+                l.remove_first(&6);
+            },
+            |l| {
+                l.pop_front();
+            },
+            |l| drop(l.split_off(1)),
+            |l| l.prepend(LinkedList::from(vec![4, 3])),
+            |l| l.resize(6, 8),
+            |l| l.retain(|v| *v != 8),
+            |l| {
+                l.pop();
+                l.pop();
+            },
+            |l| l.clear(),
+            |l| l.push_front(1),
+        ];
+
+        for step in steps {
+            step(&mut under_test);
+            assert_back_matches_last(&mut under_test);
+        }
+    }
+
+    #[test]
+    fn extract_if_keeps_back_in_sync() {
+        for removed in 0..4 {
+            let mut under_test = LinkedList::from(vec![0, 1, 2, 3]);
+
+            under_test.retain(|v| *v != removed);
+
+            assert_back_matches_last(&mut under_test);
+            under_test.push(9);
+            assert_that(&under_test.iter().nth(3)).contains_value(&9);
+        }
+    }
+
+    #[test]
+    fn back_survives_moving_the_list() {
+        let mut list = LinkedList::from(vec![1, 2, 3]);
+        let mut moved = vec![std::mem::take(&mut list)];
+        let mut under_test = moved.pop().unwrap();
+
+        under_test.push(4);
+
+        assert_that(&under_test.back()).contains_value(&4);
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn pushes_millions_of_elements_at_the_back_in_linear_time() {
+        let mut under_test = LinkedList::new();
+
+        (0..2_000_000).for_each(|v| under_test.push(v));
+
+        assert_that(&under_test.len()).is_equal_to(2_000_000);
+        assert_that(&under_test.back()).contains_value(&1_999_999);
+        assert_that(&under_test.front()).contains_value(&0);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn appends_in_constant_time_per_call() {
+        let mut under_test = LinkedList::new();
+
+        for v in 0..200_000 {
+            under_test.append(&mut LinkedList::from(vec![v, v]));
+        }
+
+        assert_that(&under_test.len()).is_equal_to(400_000);
+        assert_that(&under_test.back()).contains_value(&199_999);
+    }
+
+    #[test]
+    fn writes_through_back_after_mutable_walks_over_last_node() {
+        let mut under_test = LinkedList::from(vec![1, 2, 3]);
+
+        under_test.iter_mut().for_each(|v| *v += 1);
+        under_test.push(5);
+        *under_test.get_mut(3).unwrap() += 1;
+        under_test[3] += 1;
+        *under_test.back_mut().unwrap() += 1;
+        under_test.clone_from(&LinkedList::from(vec![0, 0, 0, 0, 0]));
+        under_test.push(1);
+        under_test.retain(|v| *v == 0);
+        under_test.append(&mut LinkedList::from(vec![2]));
+        under_test.dedup();
+        under_test.push(3);
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![0, 2, 3]));
+        assert_back_matches_last(&mut under_test);
+    }
+
+    #[test]
+    fn list_and_borrowing_iterators_are_send_and_sync() {
+        fn assert_send_sync<S: Send + Sync>() {}
+
+        assert_send_sync::<LinkedList<i32>>();
+        assert_send_sync::<Iter<'_, i32>>();
+        assert_send_sync::<IterMut<'_, i32>>();
+        assert_send_sync::<IntoIter<i32>>();
+    }
+
+    #[test]
+    fn sends_list_to_another_thread() {
+        let under_test = LinkedList::from(vec![1, 2, 3]);
+
+        let handle = std::thread::spawn(move || {
+            let mut list = under_test;
+            list.push(4);
+            list
+        });
+
+        assert_that(&handle.join().unwrap()).is_equal_to(LinkedList::from(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn collects_a_million_items() {
+        let under_test: LinkedList<usize> = (0..1_000_000).collect();
+
+        assert_that(&under_test.len()).is_equal_to(1_000_000);
+        assert_that(&under_test.back()).contains_value(&999_999);
+        assert_that(
+            &under_test
+                .iter()
+                .step_by(250_000)
+                .copied()
+                .collect::<Vec<_>>(),
+        )
+        .is_equal_to(vec![0, 250_000, 500_000, 750_000]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn builds_a_million_items_with_from() {
+        let under_test = LinkedList::from(0..1_000_000);
+
+        assert_that(&under_test.len()).is_equal_to(1_000_000);
+        assert_that(&under_test.back()).contains_value(&999_999);
+    }
+
+    #[test]
+    fn nodes_hold_only_a_value_and_a_link() {
+        assert_that(&std::mem::size_of::<Link<u64>>()).is_equal_to(std::mem::size_of::<usize>());
+        assert_that(&std::mem::size_of::<Node<u64>>())
+            .is_equal_to(std::mem::size_of::<u64>() + std::mem::size_of::<usize>());
+    }
+
+    #[test]
+    fn interleave_and_detach_keep_back_in_sync() {
+        let mut under_test =
+            LinkedList::from(vec![1, 3]).interleave(LinkedList::from(vec![2, 4, 6]));
+        assert_back_matches_last(&mut under_test);
+
+        let mut back = under_test.split_off(2);
+        assert_back_matches_last(&mut under_test);
+        assert_back_matches_last(&mut back);
+
+        under_test.push(10);
+        back.push(11);
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 10]));
+        assert_that(&back).is_equal_to(LinkedList::from(vec![3, 4, 6, 11]));
+    }
 }
@@ -0,0 +1,54 @@
+// Formatting, comparison and hashing only need `iter` and `len`, so every list
+// flavour gets them from this one place instead of keeping copies in sync.
+macro_rules! impl_list_traits {
+    ($list:ident) => {
+        impl<T: ::std::fmt::Debug> ::std::fmt::Debug for $list<T> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(concat!(stringify!($list), " "))?;
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        impl<T: ::std::fmt::Display> ::std::fmt::Display for $list<T> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str("[")?;
+                for (index, value) in self.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(" -> ")?;
+                    }
+                    value.fmt(f)?;
+                }
+                f.write_str("]")
+            }
+        }
+
+        impl<T: PartialEq<U>, U> PartialEq<$list<U>> for $list<T> {
+            fn eq(&self, other: &$list<U>) -> bool {
+                self.len() == other.len() && self.iter().eq(other.iter())
+            }
+        }
+
+        impl<T: Eq> Eq for $list<T> {}
+
+        impl<T: PartialOrd> PartialOrd for $list<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+                self.iter().partial_cmp(other.iter())
+            }
+        }
+
+        impl<T: Ord> Ord for $list<T> {
+            fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                self.iter().cmp(other.iter())
+            }
+        }
+
+        impl<T: ::std::hash::Hash> ::std::hash::Hash for $list<T> {
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                self.len().hash(state);
+                self.iter().for_each(|value| value.hash(state));
+            }
+        }
+    };
+}
+
+pub(crate) use impl_list_traits;
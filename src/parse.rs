@@ -0,0 +1,138 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::LinkedList;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseListError<E> {
+    index: usize,
+    token: String,
+    source: E,
+}
+
+impl<E> ParseListError<E> {
+    pub(crate) fn new(index: usize, token: &str, source: E) -> Self {
+        ParseListError {
+            index,
+            token: token.to_string(),
+            source,
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn source_error(&self) -> &E {
+        &self.source
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ParseListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid element {:?} at index {}: {}",
+            self.token, self.index, self.source
+        )
+    }
+}
+
+impl<E: Error + 'static> Error for ParseListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl<T: FromStr> LinkedList<T> {
+    // Tokens are trimmed before parsing; blank input yields an empty list.
+    pub fn parse_delimited(s: &str, separator: &str) -> Result<Self, ParseListError<T::Err>> {
+        assert!(!separator.is_empty(), "separator must be non-empty");
+
+        if s.trim().is_empty() {
+            return Ok(LinkedList::new());
+        }
+
+        let mut list = LinkedList::new();
+
+        for (index, token) in s.split(separator).enumerate() {
+            let token = token.trim();
+            let value = token
+                .parse()
+                .map_err(|source| ParseListError::new(index, token, source))?;
+            list.push(value);
+        }
+
+        Ok(list)
+    }
+}
+
+impl<T: FromStr> FromStr for LinkedList<T> {
+    type Err = ParseListError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LinkedList::parse_delimited(s, ",")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::ParseIntError;
+
+    use speculoos::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn parses_comma_separated_values() {
+        let under_test: LinkedList<i32> = "1, 2,3".parse().unwrap();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn parses_blank_input_as_empty_list() {
+        let under_test: LinkedList<i32> = "  ".parse().unwrap();
+
+        assert_that(&under_test.is_empty()).is_true();
+    }
+
+    #[test]
+    fn parses_values_with_custom_separator() {
+        let under_test = LinkedList::<String>::parse_delimited("a -> b -> c", "->").unwrap();
+
+        assert_that(&(under_test == vec!["a", "b", "c"])).is_true();
+    }
+
+    #[test]
+    fn reports_failing_token() {
+        let under_test = "1,2,x,4".parse::<LinkedList<u8>>().unwrap_err();
+
+        assert_that(&under_test.index()).is_equal_to(2);
+        assert_that(&under_test.token()).is_equal_to("x");
+        assert_that(&under_test.to_string()).is_equal_to(
+            "invalid element \"x\" at index 2: invalid digit found in string".to_string(),
+        );
+    }
+
+    #[test]
+    fn exposes_element_error_as_source() {
+        let under_test: ParseListError<ParseIntError> =
+            "1,,2".parse::<LinkedList<i32>>().unwrap_err();
+
+        assert_that(&under_test.token()).is_equal_to("");
+        assert_that(&Error::source(&under_test).is_some()).is_true();
+        assert_that(&under_test.source_error()).is_equal_to(&"".parse::<i32>().unwrap_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "separator must be non-empty")]
+    fn panics_on_empty_separator() {
+        let _ = LinkedList::<u8>::parse_delimited("12", "");
+    }
+}
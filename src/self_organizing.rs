@@ -0,0 +1,84 @@
+use crate::LinkedList;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Reorganization {
+    MoveToFront,
+    Transpose,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct SelfOrganizingList<T> {
+    list: LinkedList<T>,
+    reorganization: Reorganization,
+}
+
+impl<T> SelfOrganizingList<T> {
+    pub fn new(list: LinkedList<T>, reorganization: Reorganization) -> Self {
+        SelfOrganizingList {
+            list,
+            reorganization,
+        }
+    }
+
+    pub fn find<P: FnMut(&T) -> bool>(&mut self, pred: P) -> Option<&T> {
+        let index = self.list.position(pred)?;
+
+        let index = match self.reorganization {
+            Reorganization::MoveToFront => {
+                self.list.move_to_front(index);
+                0
+            }
+            Reorganization::Transpose if index > 0 => {
+                self.list.swap(index - 1, index);
+                index - 1
+            }
+            Reorganization::Transpose => index,
+        };
+
+        self.list.get(index)
+    }
+
+    pub fn list(&self) -> &LinkedList<T> {
+        &self.list
+    }
+
+    pub fn into_list(self) -> LinkedList<T> {
+        self.list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use speculoos::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn moves_found_element_to_front() {
+        let mut under_test =
+            SelfOrganizingList::new(LinkedList::from(vec![1, 2, 3]), Reorganization::MoveToFront);
+
+        assert_that(&under_test.find(|v| *v == 3)).contains(&3);
+        assert_that(under_test.list()).is_equal_to(LinkedList::from(vec![3, 1, 2]));
+    }
+
+    #[test]
+    fn transposes_found_element_with_predecessor() {
+        let mut under_test =
+            SelfOrganizingList::new(LinkedList::from(vec![1, 2, 3]), Reorganization::Transpose);
+
+        assert_that(&under_test.find(|v| *v == 3)).contains(&3);
+        assert_that(&under_test.find(|v| *v == 3)).contains(&3);
+        assert_that(&under_test.find(|v| *v == 3)).contains(&3);
+        assert_that(&under_test.into_list()).is_equal_to(LinkedList::from(vec![3, 1, 2]));
+    }
+
+    #[test]
+    fn leaves_list_unchanged_when_nothing_is_found() {
+        let mut under_test =
+            SelfOrganizingList::new(LinkedList::from(vec![1, 2]), Reorganization::MoveToFront);
+
+        assert_that(&under_test.find(|v| *v == 3)).is_none();
+        assert_that(under_test.list()).is_equal_to(LinkedList::from(vec![1, 2]));
+    }
+}
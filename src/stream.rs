@@ -0,0 +1,179 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io::{self, BufRead, Read, Write};
+use std::str::FromStr;
+
+use crate::{LinkedList, ParseListError};
+
+impl Read for LinkedList<u8> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut read = 0;
+
+        for slot in buf.iter_mut() {
+            match self.pop_front() {
+                Some(byte) => *slot = byte,
+                None => break,
+            }
+            read += 1;
+        }
+
+        Ok(read)
+    }
+}
+
+impl Write for LinkedList<u8> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.extend(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl LinkedList<String> {
+    pub fn from_lines<R: BufRead>(reader: R) -> io::Result<Self> {
+        LinkedList::parse_lines(reader)
+    }
+}
+
+impl<T: FromStr> LinkedList<T>
+where
+    T::Err: Error + Send + Sync + 'static,
+{
+    // a line that fails to parse surfaces as InvalidData wrapping a
+    // ParseListError that carries the zero-based line index
+    pub fn parse_lines<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut list = LinkedList::new();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let value = line.parse().map_err(|source| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    ParseListError::new(index, &line, source),
+                )
+            })?;
+            list.push(value);
+        }
+
+        Ok(list)
+    }
+}
+
+impl<T: Display> LinkedList<T> {
+    pub fn write_lines<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        for value in self.iter() {
+            writeln!(writer, "{value}")?;
+        }
+
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use speculoos::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn writes_append_bytes_at_the_back() {
+        let mut under_test = LinkedList::from(vec![b'a']);
+
+        under_test.write_all(b"bc").unwrap();
+        write!(under_test, "{}", 1).unwrap();
+        under_test.flush().unwrap();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(b"abc1".to_vec()));
+    }
+
+    #[test]
+    fn reads_drain_bytes_from_the_front() {
+        let mut under_test = LinkedList::from(b"hello".to_vec());
+        let mut buf = [0; 3];
+
+        assert_that(&under_test.read(&mut buf).unwrap()).is_equal_to(3);
+        assert_that(&buf).is_equal_to(*b"hel");
+        assert_that(&under_test).is_equal_to(LinkedList::from(b"lo".to_vec()));
+    }
+
+    #[test]
+    fn reads_partially_then_reports_end_of_stream() {
+        let mut under_test = LinkedList::from(b"hi".to_vec());
+        let mut buf = [0; 4];
+
+        assert_that(&under_test.read(&mut buf).unwrap()).is_equal_to(2);
+        assert_that(&under_test.read(&mut buf).unwrap()).is_equal_to(0);
+        assert_that(&under_test.is_empty()).is_true();
+    }
+
+    #[test]
+    fn acts_as_fifo_buffer_between_io_adapters() {
+        let mut under_test = LinkedList::new();
+
+        io::copy(&mut &b"first line\nsecond"[..], &mut under_test).unwrap();
+        let mut out = String::new();
+        under_test.read_to_string(&mut out).unwrap();
+
+        assert_that(&out).is_equal_to("first line\nsecond".to_string());
+    }
+
+    #[test]
+    fn reads_lines_into_list() {
+        let under_test = LinkedList::from_lines("first\nsecond\r\n\nlast".as_bytes()).unwrap();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![
+            "first".to_string(),
+            "second".to_string(),
+            String::new(),
+            "last".to_string(),
+        ]));
+    }
+
+    #[test]
+    fn reads_no_lines_from_empty_input() {
+        let under_test = LinkedList::from_lines(io::empty()).unwrap();
+
+        assert_that(&under_test.is_empty()).is_true();
+    }
+
+    #[test]
+    fn parses_lines_into_values() {
+        let under_test = LinkedList::<u32>::parse_lines("1\n22\n333\n".as_bytes()).unwrap();
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![1, 22, 333]));
+    }
+
+    #[test]
+    fn reports_line_that_fails_to_parse() {
+        let error = LinkedList::<u32>::parse_lines("1\ntwo\n3".as_bytes()).unwrap_err();
+
+        assert_that(&error.kind()).is_equal_to(io::ErrorKind::InvalidData);
+        assert_that(&error.to_string()).is_equal_to(
+            "invalid element \"two\" at index 1: invalid digit found in string".to_string(),
+        );
+    }
+
+    #[test]
+    fn writes_one_line_per_element() {
+        let mut out = Vec::new();
+
+        LinkedList::from(vec![1.5, 2.0])
+            .write_lines(&mut out)
+            .unwrap();
+
+        assert_that(&out).is_equal_to(b"1.5\n2\n".to_vec());
+    }
+
+    #[test]
+    fn round_trips_lines() {
+        let under_test = LinkedList::from(vec!["a b".to_string(), "c".to_string()]);
+        let mut out = Vec::new();
+
+        under_test.write_lines(&mut out).unwrap();
+
+        assert_that(&LinkedList::from_lines(out.as_slice()).unwrap()).is_equal_to(under_test);
+    }
+}
@@ -0,0 +1,95 @@
+use std::string::FromUtf8Error;
+
+use crate::LinkedList;
+
+impl LinkedList<char> {
+    pub fn from_str_chars(s: &str) -> Self {
+        LinkedList::build(s.chars())
+    }
+
+    pub fn to_string_lossless(&self) -> String {
+        let mut string = String::with_capacity(self.iter().map(|c| c.len_utf8()).sum());
+        string.extend(self.iter());
+
+        string
+    }
+}
+
+impl LinkedList<u8> {
+    pub fn from_str_bytes(s: &str) -> Self {
+        LinkedList::build(s.bytes())
+    }
+
+    pub fn into_string(self) -> Result<String, FromUtf8Error> {
+        String::from_utf8(self.into_vec())
+    }
+
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf8_lossy(&self.to_vec()).into_owned()
+    }
+}
+
+impl From<LinkedList<char>> for String {
+    fn from(list: LinkedList<char>) -> Self {
+        list.to_string_lossless()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use speculoos::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn builds_list_of_chars_from_str() {
+        let under_test = LinkedList::from_str_chars("héllo");
+
+        assert_that(&under_test.len()).is_equal_to(5);
+        assert_that(&under_test.get(1)).contains_value(&'é');
+    }
+
+    #[test]
+    fn renders_chars_back_into_string() {
+        let mut under_test = LinkedList::from_str_chars("stressed");
+        under_test.reverse();
+
+        assert_that(&under_test.to_string_lossless()).is_equal_to("desserts".to_string());
+        assert_that(&String::from(under_test)).is_equal_to("desserts".to_string());
+    }
+
+    #[test]
+    fn builds_list_of_bytes_from_str() {
+        let under_test = LinkedList::from_str_bytes("hé");
+
+        assert_that(&under_test).is_equal_to(LinkedList::from(vec![b'h', 0xc3, 0xa9]));
+    }
+
+    #[test]
+    fn converts_valid_utf8_bytes_into_string() {
+        let under_test = LinkedList::from_str_bytes("héllo");
+
+        assert_that(&under_test.into_string()).is_ok_containing("héllo".to_string());
+    }
+
+    #[test]
+    fn rejects_invalid_utf8_bytes() {
+        let under_test = LinkedList::from(vec![b'a', 0xff]);
+
+        assert_that(&under_test.into_string().is_err()).is_true();
+    }
+
+    #[test]
+    fn converts_invalid_utf8_bytes_lossily() {
+        let under_test = LinkedList::from(vec![b'a', 0xff, b'b']);
+
+        assert_that(&under_test.to_string_lossy()).is_equal_to("a\u{fffd}b".to_string());
+    }
+
+    #[test]
+    fn round_trips_bytes_through_vec() {
+        let under_test: LinkedList<u8> = b"bytes".to_vec().into();
+
+        assert_that(&Vec::from(under_test)).is_equal_to(b"bytes".to_vec());
+    }
+}
@@ -0,0 +1,945 @@
+use std::collections::{vec_deque, VecDeque};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+use std::ptr::NonNull;
+
+use crate::macros::impl_list_traits;
+use crate::LinkedList;
+
+const CHUNK_CAPACITY: usize = 16;
+
+pub struct UnrolledLinkedList<T> {
+    head: ChunkLink<T>,
+    len: usize,
+    tail: ChunkLink<T>,
+}
+
+// SAFETY: the list is the sole owner of every chunk in its chain and of the
+// buffers inside them, so handing it to another thread hands over only `T`s.
+unsafe impl<T: Send> Send for UnrolledLinkedList<T> {}
+
+// SAFETY: through `&UnrolledLinkedList<T>` chunks are only read, never relinked
+// or resized, and the only values reachable are `&T`s.
+unsafe impl<T: Sync> Sync for UnrolledLinkedList<T> {}
+
+type ChunkLink<T> = Option<NonNull<Chunk<T>>>;
+
+// Chunks are never left empty: one that loses its last element is unlinked.
+// They are owned through raw pointers, like `LinkedList`'s nodes, so `prev`,
+// `next` and `tail` never go stale when a chunk is reached another way.
+struct Chunk<T> {
+    items: VecDeque<T>,
+    prev: ChunkLink<T>,
+    next: ChunkLink<T>,
+}
+
+impl<T> Chunk<T> {
+    fn alloc(items: VecDeque<T>, prev: ChunkLink<T>, next: ChunkLink<T>) -> NonNull<Self> {
+        NonNull::from(Box::leak(Box::new(Chunk { items, prev, next })))
+    }
+
+    fn items_with(value: T) -> VecDeque<T> {
+        let mut items = VecDeque::with_capacity(CHUNK_CAPACITY);
+        items.push_back(value);
+
+        items
+    }
+
+    fn is_full(&self) -> bool {
+        self.items.len() == CHUNK_CAPACITY
+    }
+}
+
+impl<T> Drop for UnrolledLinkedList<T> {
+    fn drop(&mut self) {
+        while let Some(chunk) = self.head {
+            // SAFETY: `chunk` is the live head of this list, unlinked before it
+            // is freed
+            unsafe {
+                self.head = (*chunk.as_ptr()).next;
+                drop(Box::from_raw(chunk.as_ptr()));
+            }
+        }
+    }
+}
+
+impl<T> UnrolledLinkedList<T> {
+    pub fn new() -> Self {
+        UnrolledLinkedList {
+            head: None,
+            len: 0,
+            tail: None,
+        }
+    }
+
+    // SAFETY: callers must pass `None` or a live chunk of this list
+    unsafe fn link_after(&mut self, prev: ChunkLink<T>, items: VecDeque<T>) {
+        let next = match prev {
+            Some(prev) => (*prev.as_ptr()).next,
+            None => self.head,
+        };
+        let chunk = Chunk::alloc(items, prev, next);
+
+        match prev {
+            Some(prev) => (*prev.as_ptr()).next = Some(chunk),
+            None => self.head = Some(chunk),
+        }
+        match next {
+            Some(next) => (*next.as_ptr()).prev = Some(chunk),
+            None => self.tail = Some(chunk),
+        }
+    }
+
+    // SAFETY: callers must pass a live chunk of this list; it is freed here
+    unsafe fn unlink(&mut self, chunk: NonNull<Chunk<T>>) {
+        let Chunk { prev, next, .. } = *Box::from_raw(chunk.as_ptr());
+
+        match prev {
+            Some(prev) => (*prev.as_ptr()).next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => (*next.as_ptr()).prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    // Tops up a chunk that fell below half full from a neighbour, or merges the
+    // two when they fit in one chunk, so removals do not leave a trail of
+    // nearly empty chunks behind.
+    //
+    // SAFETY: callers must pass a live, non-empty chunk of this list
+    unsafe fn rebalance(&mut self, chunk: NonNull<Chunk<T>>) {
+        let (left, right) = match ((*chunk.as_ptr()).prev, (*chunk.as_ptr()).next) {
+            (_, Some(next)) => (chunk, next),
+            (Some(prev), None) => (prev, chunk),
+            (None, None) => return,
+        };
+        let left_items = &mut (*left.as_ptr()).items;
+        let right_items = &mut (*right.as_ptr()).items;
+
+        if left_items.len() + right_items.len() <= CHUNK_CAPACITY {
+            left_items.append(right_items);
+            self.unlink(right);
+        } else if left_items.len() < CHUNK_CAPACITY / 2 {
+            let moved = CHUNK_CAPACITY / 2 - left_items.len();
+            left_items.extend(right_items.drain(..moved));
+        } else {
+            let moved = CHUNK_CAPACITY / 2 - right_items.len();
+            let start = left_items.len() - moved;
+            left_items
+                .drain(start..)
+                .rev()
+                .for_each(|value| right_items.push_front(value));
+        }
+    }
+
+    // finds the chunk holding `index` and the position of `index` inside it
+    fn locate(&self, mut index: usize) -> Option<(NonNull<Chunk<T>>, usize)> {
+        let mut chunk = self.head;
+        while let Some(current) = chunk {
+            // SAFETY: every linked chunk stays allocated while the list owns it
+            let len = unsafe { (*current.as_ptr()).items.len() };
+            if index < len {
+                return Some((current, index));
+            }
+            index -= len;
+            chunk = unsafe { (*current.as_ptr()).next };
+        }
+
+        None
+    }
+
+    pub fn push(&mut self, val: T) {
+        match self.tail {
+            // SAFETY: `tail` is the live last chunk of this list
+            Some(tail) if unsafe { !(*tail.as_ptr()).is_full() } => unsafe {
+                (*tail.as_ptr()).items.push_back(val);
+            },
+            // SAFETY: `tail` is `None` or a live chunk of this list
+            tail => unsafe { self.link_after(tail, Chunk::items_with(val)) },
+        }
+        self.len += 1;
+    }
+
+    pub fn push_front(&mut self, val: T) {
+        match self.head {
+            // SAFETY: `head` is the live first chunk of this list
+            Some(head) if unsafe { !(*head.as_ptr()).is_full() } => unsafe {
+                (*head.as_ptr()).items.push_front(val);
+            },
+            // SAFETY: linking after `None` puts the chunk at the front
+            _ => unsafe { self.link_after(None, Chunk::items_with(val)) },
+        }
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        // SAFETY: `tail` is the live last chunk of this list, and is unlinked
+        // only once it has been emptied
+        unsafe {
+            let value = (*tail.as_ptr()).items.pop_back();
+            self.len -= 1;
+
+            if (*tail.as_ptr()).items.is_empty() {
+                self.unlink(tail);
+            }
+
+            value
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head?;
+        // SAFETY: `head` is the live first chunk of this list, and is unlinked
+        // only once it has been emptied
+        unsafe {
+            let value = (*head.as_ptr()).items.pop_front();
+            self.len -= 1;
+
+            if (*head.as_ptr()).items.is_empty() {
+                self.unlink(head);
+            }
+
+            value
+        }
+    }
+
+    pub fn insert(&mut self, index: usize, val: T) {
+        assert!(
+            index <= self.len,
+            "insertion index (is {index}) should be <= len (is {})",
+            self.len
+        );
+
+        if index == self.len {
+            return self.push(val);
+        }
+
+        let (chunk, offset) = self.locate(index).unwrap();
+        // SAFETY: `chunk` is a live chunk of this list, and the unique borrow of
+        // the list keeps its buffer unaliased
+        let items = unsafe { &mut (*chunk.as_ptr()).items };
+        self.len += 1;
+
+        if items.len() < CHUNK_CAPACITY {
+            return items.insert(offset, val);
+        }
+
+        // a full chunk is split in half so neighbouring inserts stay cheap
+        let mut back = VecDeque::with_capacity(CHUNK_CAPACITY);
+        back.extend(items.drain(CHUNK_CAPACITY / 2..));
+        match offset.checked_sub(CHUNK_CAPACITY / 2) {
+            Some(offset) => back.insert(offset, val),
+            None => items.insert(offset, val),
+        }
+
+        // SAFETY: `chunk` is a live chunk of this list
+        unsafe { self.link_after(Some(chunk), back) };
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let (chunk, offset) = self.locate(index)?;
+        // SAFETY: `chunk` is a live chunk of this list, and is unlinked only
+        // once it has been emptied
+        unsafe {
+            let value = (*chunk.as_ptr()).items.remove(offset);
+            self.len -= 1;
+
+            match (*chunk.as_ptr()).items.len() {
+                0 => self.unlink(chunk),
+                len if len < CHUNK_CAPACITY / 2 => self.rebalance(chunk),
+                _ => {}
+            }
+
+            value
+        }
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        // SAFETY: `head` is a live chunk of this list, which the borrow of the
+        // list keeps from being modified while the reference is alive
+        self.head
+            .and_then(|chunk| unsafe { (*chunk.as_ptr()).items.front() })
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: as for `front`, with the unique borrow of the list making the
+        // reference unique too
+        self.head
+            .and_then(|chunk| unsafe { (*chunk.as_ptr()).items.front_mut() })
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        // SAFETY: `tail` is a live chunk of this list, which the borrow of the
+        // list keeps from being modified while the reference is alive
+        self.tail
+            .and_then(|chunk| unsafe { (*chunk.as_ptr()).items.back() })
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: as for `back`, with the unique borrow of the list making the
+        // reference unique too
+        self.tail
+            .and_then(|chunk| unsafe { (*chunk.as_ptr()).items.back_mut() })
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (chunk, offset) = self.locate(index)?;
+
+        // SAFETY: `locate` only returns live chunks of this list, which the
+        // borrow of the list keeps from being modified
+        unsafe { (*chunk.as_ptr()).items.get(offset) }
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let (chunk, offset) = self.locate(index)?;
+
+        // SAFETY: as for `get`, with the unique borrow of the list making the
+        // reference unique too
+        unsafe { (*chunk.as_ptr()).items.get_mut(offset) }
+    }
+
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|v| v == value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn clear(&mut self) {
+        *self = UnrolledLinkedList::new();
+    }
+
+    pub fn iter(&self) -> UnrolledIter<'_, T> {
+        UnrolledIter {
+            front: self.head,
+            back: self.tail,
+            front_items: Default::default(),
+            back_items: Default::default(),
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> UnrolledIterMut<'_, T> {
+        UnrolledIterMut {
+            front: self.head,
+            back: self.tail,
+            front_items: Default::default(),
+            back_items: Default::default(),
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for UnrolledLinkedList<T> {
+    fn default() -> Self {
+        UnrolledLinkedList::new()
+    }
+}
+
+impl<T: Clone> Clone for UnrolledLinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl_list_traits!(UnrolledLinkedList);
+
+impl<T> Index<usize> for UnrolledLinkedList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len;
+        self.get(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+impl<T> IndexMut<usize> for UnrolledLinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        self.get_mut(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+impl<T> FromIterator<T> for UnrolledLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = UnrolledLinkedList::new();
+        list.extend(iter);
+
+        list
+    }
+}
+
+impl<T> Extend<T> for UnrolledLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|v| self.push(v));
+    }
+}
+
+impl<T> From<LinkedList<T>> for UnrolledLinkedList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<UnrolledLinkedList<T>> for LinkedList<T> {
+    fn from(list: UnrolledLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> IntoIterator for UnrolledLinkedList<T> {
+    type Item = T;
+    type IntoIter = UnrolledIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        UnrolledIntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a UnrolledLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = UnrolledIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut UnrolledLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = UnrolledIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+// Both borrowing iterators open each chunk exactly once, from whichever end
+// reaches it first. `front..=back` are the chunks nobody has opened yet; once
+// that range is empty the remaining values all sit in the two open buffers.
+pub struct UnrolledIter<'a, T> {
+    front: ChunkLink<T>,
+    back: ChunkLink<T>,
+    front_items: vec_deque::Iter<'a, T>,
+    back_items: vec_deque::Iter<'a, T>,
+    len: usize,
+    marker: PhantomData<&'a T>,
+}
+
+// SAFETY: the iterator only reads chunks of a list it borrows immutably and
+// yields `&T`s, so sending it is as safe as sending a `&UnrolledLinkedList<T>`.
+unsafe impl<T: Sync> Send for UnrolledIter<'_, T> {}
+
+// SAFETY: a shared `UnrolledIter` can only be cloned, which reads the same
+// list the iterator already borrows immutably.
+unsafe impl<T: Sync> Sync for UnrolledIter<'_, T> {}
+
+impl<T> Clone for UnrolledIter<'_, T> {
+    fn clone(&self) -> Self {
+        UnrolledIter {
+            front: self.front,
+            back: self.back,
+            front_items: self.front_items.clone(),
+            back_items: self.back_items.clone(),
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for UnrolledIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = loop {
+            if let Some(value) = self.front_items.next() {
+                break value;
+            }
+
+            let Some(chunk) = self.front else {
+                break self.back_items.next()?;
+            };
+            // SAFETY: `chunk` is an unopened chunk of the borrowed list
+            unsafe {
+                self.front_items = (*chunk.as_ptr()).items.iter();
+                self.front = (*chunk.as_ptr()).next;
+            }
+            if self.back == Some(chunk) {
+                (self.front, self.back) = (None, None);
+            }
+        };
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for UnrolledIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let value = loop {
+            if let Some(value) = self.back_items.next_back() {
+                break value;
+            }
+
+            let Some(chunk) = self.back else {
+                break self.front_items.next_back()?;
+            };
+            // SAFETY: `chunk` is an unopened chunk of the borrowed list
+            unsafe {
+                self.back_items = (*chunk.as_ptr()).items.iter();
+                self.back = (*chunk.as_ptr()).prev;
+            }
+            if self.front == Some(chunk) {
+                (self.front, self.back) = (None, None);
+            }
+        };
+        self.len -= 1;
+
+        Some(value)
+    }
+}
+
+impl<T> ExactSizeIterator for UnrolledIter<'_, T> {}
+
+impl<T> FusedIterator for UnrolledIter<'_, T> {}
+
+pub struct UnrolledIterMut<'a, T> {
+    front: ChunkLink<T>,
+    back: ChunkLink<T>,
+    front_items: vec_deque::IterMut<'a, T>,
+    back_items: vec_deque::IterMut<'a, T>,
+    len: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: the iterator stands in for the `&mut UnrolledLinkedList<T>` it was
+// made from and yields disjoint `&mut T`s, so it may move across threads
+// whenever the values can.
+unsafe impl<T: Send> Send for UnrolledIterMut<'_, T> {}
+
+// SAFETY: nothing can be reached through a shared `UnrolledIterMut`, so
+// sharing it is as safe as sharing a `&mut UnrolledLinkedList<T>`.
+unsafe impl<T: Sync> Sync for UnrolledIterMut<'_, T> {}
+
+impl<'a, T> Iterator for UnrolledIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = loop {
+            if let Some(value) = self.front_items.next() {
+                break value;
+            }
+
+            let Some(chunk) = self.front else {
+                break self.back_items.next()?;
+            };
+            // SAFETY: `chunk` is a chunk of the mutably borrowed list that no
+            // buffer iterator has opened yet, so its buffer is unaliased
+            unsafe {
+                self.front_items = (*chunk.as_ptr()).items.iter_mut();
+                self.front = (*chunk.as_ptr()).next;
+            }
+            if self.back == Some(chunk) {
+                (self.front, self.back) = (None, None);
+            }
+        };
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for UnrolledIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let value = loop {
+            if let Some(value) = self.back_items.next_back() {
+                break value;
+            }
+
+            let Some(chunk) = self.back else {
+                break self.front_items.next_back()?;
+            };
+            // SAFETY: as in `next`
+            unsafe {
+                self.back_items = (*chunk.as_ptr()).items.iter_mut();
+                self.back = (*chunk.as_ptr()).prev;
+            }
+            if self.front == Some(chunk) {
+                (self.front, self.back) = (None, None);
+            }
+        };
+        self.len -= 1;
+
+        Some(value)
+    }
+}
+
+impl<T> ExactSizeIterator for UnrolledIterMut<'_, T> {}
+
+impl<T> FusedIterator for UnrolledIterMut<'_, T> {}
+
+pub struct UnrolledIntoIter<T> {
+    list: UnrolledLinkedList<T>,
+}
+
+impl<T> Iterator for UnrolledIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for UnrolledIntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop()
+    }
+}
+
+impl<T> ExactSizeIterator for UnrolledIntoIter<T> {}
+
+impl<T> FusedIterator for UnrolledIntoIter<T> {}
+
+#[cfg(test)]
+mod tests {
+    use speculoos::prelude::*;
+
+    use super::*;
+
+    fn chunk_sizes<T>(list: &UnrolledLinkedList<T>) -> Vec<usize> {
+        let mut sizes = Vec::new();
+        let mut chunk = list.head;
+        while let Some(current) = chunk {
+            // SAFETY: the borrowed list keeps its chunks alive
+            let current = unsafe { &*current.as_ptr() };
+            sizes.push(current.items.len());
+            chunk = current.next;
+        }
+
+        sizes
+    }
+
+    #[test]
+    fn pushes_and_pops_at_both_ends() {
+        let mut under_test = UnrolledLinkedList::new();
+
+        under_test.push(2);
+        under_test.push(3);
+        under_test.push_front(1);
+
+        assert_that(&under_test.len()).is_equal_to(3);
+        assert_that(&under_test.front()).contains_value(&1);
+        assert_that(&under_test.back()).contains_value(&3);
+        assert_that(&under_test.pop()).contains_value(3);
+        assert_that(&under_test.pop_front()).contains_value(1);
+        assert_that(&under_test.pop()).contains_value(2);
+        assert_that(&under_test.pop()).is_none();
+        assert_that(&under_test.pop_front()).is_none();
+        assert_that(&under_test.is_empty()).is_true();
+    }
+
+    #[test]
+    fn packs_elements_into_full_chunks() {
+        let under_test: UnrolledLinkedList<usize> = (0..CHUNK_CAPACITY * 2 + 1).collect();
+
+        assert_that(&chunk_sizes(&under_test)).is_equal_to(vec![CHUNK_CAPACITY, CHUNK_CAPACITY, 1]);
+    }
+
+    #[test]
+    fn unlinks_chunks_emptied_by_pops() {
+        let mut under_test: UnrolledLinkedList<usize> = (0..CHUNK_CAPACITY + 1).collect();
+
+        under_test.pop();
+        assert_that(&chunk_sizes(&under_test)).is_equal_to(vec![CHUNK_CAPACITY]);
+        under_test.push(99);
+        assert_that(&under_test.back()).contains_value(&99);
+
+        (0..CHUNK_CAPACITY).for_each(|_| {
+            under_test.pop_front();
+        });
+        assert_that(&chunk_sizes(&under_test)).is_equal_to(vec![1]);
+        assert_that(&under_test.front()).contains_value(&99);
+    }
+
+    #[test]
+    fn splits_full_chunk_on_middle_insert() {
+        let mut under_test: UnrolledLinkedList<usize> = (0..CHUNK_CAPACITY).collect();
+
+        under_test.insert(3, 100);
+        under_test.insert(CHUNK_CAPACITY - 1, 200);
+
+        assert_that(&chunk_sizes(&under_test))
+            .is_equal_to(vec![CHUNK_CAPACITY / 2 + 1, CHUNK_CAPACITY / 2 + 1]);
+        assert_that(&under_test[3]).is_equal_to(100);
+        assert_that(&under_test[CHUNK_CAPACITY - 1]).is_equal_to(200);
+        assert_that(&under_test.len()).is_equal_to(CHUNK_CAPACITY + 2);
+        under_test.push(300);
+        assert_that(&under_test.back()).contains_value(&300);
+    }
+
+    #[test]
+    fn inserts_at_both_ends() {
+        let mut under_test = UnrolledLinkedList::new();
+
+        under_test.insert(0, 2);
+        under_test.insert(0, 1);
+        under_test.insert(2, 3);
+
+        assert_that(&under_test.iter().collect::<Vec<_>>()).is_equal_to(vec![&1, &2, &3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+    fn panics_when_inserting_past_the_end() {
+        let mut under_test = UnrolledLinkedList::new();
+        under_test.push(1);
+
+        under_test.insert(2, 2);
+    }
+
+    #[test]
+    fn removes_from_the_middle_and_end() {
+        let mut under_test: UnrolledLinkedList<usize> = (0..CHUNK_CAPACITY + 1).collect();
+
+        assert_that(&under_test.remove(CHUNK_CAPACITY)).contains_value(CHUNK_CAPACITY);
+        assert_that(&under_test.back()).contains_value(&(CHUNK_CAPACITY - 1));
+        assert_that(&under_test.remove(1)).contains_value(1);
+        assert_that(&under_test.remove(CHUNK_CAPACITY)).is_none();
+        assert_that(&under_test.get(1)).contains_value(&2);
+        assert_that(&under_test.len()).is_equal_to(CHUNK_CAPACITY - 1);
+    }
+
+    #[test]
+    fn keeps_chunks_at_least_half_full_after_heavy_removal() {
+        let mut under_test: UnrolledLinkedList<usize> = (0..CHUNK_CAPACITY * 8).collect();
+
+        (0..CHUNK_CAPACITY * 6).for_each(|index| {
+            under_test.remove(index / 3);
+        });
+
+        let sizes = chunk_sizes(&under_test);
+        assert_that(&sizes.iter().sum::<usize>()).is_equal_to(CHUNK_CAPACITY * 2);
+        assert_that(&sizes.iter().all(|&size| size >= CHUNK_CAPACITY / 2)).is_true();
+        assert_that(&sizes.len()).is_less_than_or_equal_to(4);
+    }
+
+    #[test]
+    fn borrows_from_a_neighbour_instead_of_merging_when_both_are_too_big() {
+        let mut from_first: UnrolledLinkedList<usize> = (0..CHUNK_CAPACITY * 2).collect();
+        let mut from_last = from_first.clone();
+
+        (0..CHUNK_CAPACITY / 2 + 1).for_each(|_| {
+            from_first.remove(0);
+            from_last.remove(CHUNK_CAPACITY);
+        });
+
+        assert_that(&chunk_sizes(&from_first))
+            .is_equal_to(vec![CHUNK_CAPACITY / 2, CHUNK_CAPACITY - 1]);
+        assert_that(&from_first.iter().copied().collect::<Vec<_>>())
+            .is_equal_to((CHUNK_CAPACITY / 2 + 1..CHUNK_CAPACITY * 2).collect::<Vec<_>>());
+        assert_that(&chunk_sizes(&from_last))
+            .is_equal_to(vec![CHUNK_CAPACITY - 1, CHUNK_CAPACITY / 2]);
+        assert_that(&from_last.back()).contains_value(&(CHUNK_CAPACITY * 2 - 1));
+        assert_that(&from_last[CHUNK_CAPACITY - 1]).is_equal_to(CHUNK_CAPACITY - 1);
+    }
+
+    #[test]
+    fn indexes_and_mutates_elements() {
+        let mut under_test: UnrolledLinkedList<usize> = (0..40).collect();
+
+        under_test[35] = 0;
+        *under_test.front_mut().unwrap() = 1;
+        *under_test.back_mut().unwrap() = 2;
+        under_test.iter_mut().for_each(|v| *v += 1);
+
+        assert_that(&under_test[0]).is_equal_to(2);
+        assert_that(&under_test[35]).is_equal_to(1);
+        assert_that(&under_test[39]).is_equal_to(3);
+        assert_that(&under_test.get(40)).is_none();
+    }
+
+    #[test]
+    fn iterates_across_chunks_in_order() {
+        let under_test: UnrolledLinkedList<usize> = (0..50).collect();
+
+        assert_that(&under_test.iter().copied().collect::<Vec<_>>())
+            .is_equal_to((0..50).collect::<Vec<_>>());
+        assert_that(&under_test.iter().len()).is_equal_to(50);
+        assert_that(
+            &under_test
+                .clone()
+                .into_iter()
+                .rev()
+                .take(2)
+                .collect::<Vec<_>>(),
+        )
+        .is_equal_to(vec![49, 48]);
+    }
+
+    #[test]
+    fn iterates_from_both_ends_across_chunks() {
+        let under_test: UnrolledLinkedList<usize> = (0..CHUNK_CAPACITY * 3).collect();
+
+        assert_that(&under_test.iter().rev().copied().collect::<Vec<_>>())
+            .is_equal_to((0..CHUNK_CAPACITY * 3).rev().collect::<Vec<_>>());
+
+        for split in [
+            0,
+            1,
+            CHUNK_CAPACITY,
+            CHUNK_CAPACITY * 3 / 2,
+            CHUNK_CAPACITY * 3,
+        ] {
+            let mut iter = under_test.iter();
+            let front: Vec<_> = iter.by_ref().take(split).copied().collect();
+            let back: Vec<_> = iter.rev().copied().collect();
+
+            assert_that(&front).is_equal_to((0..split).collect::<Vec<_>>());
+            assert_that(&back).is_equal_to((split..CHUNK_CAPACITY * 3).rev().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn alternates_ends_without_overlap() {
+        let mut under_test: UnrolledLinkedList<usize> = (0..CHUNK_CAPACITY + 3).collect();
+        let mut iter = under_test.iter();
+        let mut seen = Vec::new();
+
+        while let Some(front) = iter.next() {
+            seen.push(*front);
+            seen.extend(iter.next_back().copied());
+        }
+        seen.sort();
+
+        assert_that(&seen).is_equal_to((0..CHUNK_CAPACITY + 3).collect::<Vec<_>>());
+
+        let mut iter = under_test.iter_mut();
+        while let Some(front) = iter.next() {
+            *front += 100;
+            if let Some(back) = iter.next_back() {
+                *back += 1000;
+            }
+        }
+
+        assert_that(&under_test[0]).is_equal_to(100);
+        assert_that(&under_test[CHUNK_CAPACITY + 2]).is_equal_to(CHUNK_CAPACITY + 1002);
+        assert_that(&under_test.iter().len()).is_equal_to(CHUNK_CAPACITY + 3);
+    }
+
+    #[test]
+    fn mutates_from_the_back_across_chunks() {
+        let mut under_test: UnrolledLinkedList<usize> = (0..40).collect();
+
+        under_test
+            .iter_mut()
+            .rev()
+            .enumerate()
+            .for_each(|(index, v)| *v = index);
+
+        assert_that(&under_test.iter().copied().collect::<Vec<_>>())
+            .is_equal_to((0..40).rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn keeps_back_on_new_chunk_after_splitting_the_tail() {
+        let mut under_test: UnrolledLinkedList<usize> = (0..CHUNK_CAPACITY).collect();
+
+        under_test.insert(CHUNK_CAPACITY - 1, 100);
+        under_test.push(200);
+        under_test.pop();
+        under_test.pop();
+
+        assert_that(&under_test.back()).contains_value(&100);
+        assert_that(&under_test.iter().rev().nth(1)).contains_value(&(CHUNK_CAPACITY - 2));
+    }
+
+    #[test]
+    fn pushes_after_mutable_walks_over_last_chunk() {
+        let mut under_test: UnrolledLinkedList<usize> = (0..3).collect();
+
+        under_test.iter_mut().for_each(|v| *v += 1);
+        under_test.push(4);
+        under_test[3] += 1;
+        under_test.push_front(0);
+        *under_test.front_mut().unwrap() += 1;
+        under_test.push(6);
+
+        assert_that(&under_test.iter().copied().collect::<Vec<_>>())
+            .is_equal_to(vec![1, 1, 2, 3, 5, 6]);
+    }
+
+    #[test]
+    fn list_and_borrowing_iterators_are_send_and_sync() {
+        fn assert_send_sync<S: Send + Sync>() {}
+
+        assert_send_sync::<UnrolledLinkedList<i32>>();
+        assert_send_sync::<UnrolledIter<'_, i32>>();
+        assert_send_sync::<UnrolledIterMut<'_, i32>>();
+        assert_send_sync::<UnrolledIntoIter<i32>>();
+    }
+
+    #[test]
+    fn shares_trait_surface_with_linked_list() {
+        let under_test: UnrolledLinkedList<i32> = (1..=3).collect();
+
+        assert_that(&format!("{under_test:?}"))
+            .is_equal_to("UnrolledLinkedList [1, 2, 3]".to_string());
+        assert_that(&under_test.to_string()).is_equal_to("[1 -> 2 -> 3]".to_string());
+        assert_that(&under_test).is_equal_to(under_test.clone());
+        assert_that(&(under_test < (1..=4).collect())).is_true();
+        let converted: LinkedList<_> = under_test.into();
+
+        assert_that(&converted).is_equal_to(LinkedList::from(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn clears_list() {
+        let mut under_test: UnrolledLinkedList<i32> = (0..100).collect();
+
+        under_test.clear();
+        under_test.push(1);
+
+        assert_that(&under_test.len()).is_equal_to(1);
+        assert_that(&chunk_sizes(&under_test)).is_equal_to(vec![1]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn handles_millions_of_elements() {
+        let under_test: UnrolledLinkedList<u32> = (0..5_000_000).collect();
+
+        assert_that(&under_test.back()).contains_value(&4_999_999);
+        assert_that(&under_test.get(4_000_000)).contains_value(&4_000_000);
+        assert_that(&chunk_sizes(&under_test).len()).is_equal_to(5_000_000 / CHUNK_CAPACITY);
+    }
+}